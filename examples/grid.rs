@@ -0,0 +1,57 @@
+// Grid UI example.
+
+use actuate::prelude::*;
+use bevy::{prelude::*, winit::WinitSettings};
+
+// Dashboard card.
+fn card<'a>(title: &str) -> impl Compose + Modify<'a> {
+    container(text::title(title))
+        .width(Val::Percent(100.))
+        .height(Val::Percent(100.))
+}
+
+// Dashboard composable with a 3x2 grid of cards.
+#[derive(Data)]
+struct Dashboard;
+
+impl Compose for Dashboard {
+    fn compose(_cx: Scope<Self>) -> impl Compose {
+        grid((
+            // Span the first two columns of the first row.
+            card("Revenue").grid_column(GridPlacement::span(2)),
+            // Span both rows of the last column.
+            card("Activity").grid_row(GridPlacement::span(2)),
+            card("Users"),
+            card("Orders"),
+        ))
+        .columns(vec![RepeatedGridTrack::flex(3, 1.)])
+        .rows(vec![RepeatedGridTrack::flex(2, 1.)])
+        .row_gap(Val::Px(10.))
+        .column_gap(Val::Px(10.))
+        .width(Val::Percent(100.))
+        .height(Val::Percent(100.))
+    }
+}
+
+fn setup(mut commands: Commands) {
+    commands.spawn(Camera2d::default());
+
+    // Spawn a composition with a `Dashboard`, adding it to the Actuate runtime.
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            padding: UiRect::all(Val::Px(10.)),
+            ..default()
+        },
+        Composition::new(Dashboard),
+    ));
+}
+
+fn main() {
+    App::new()
+        .add_plugins((DefaultPlugins, ActuatePlugin))
+        .insert_resource(WinitSettings::desktop_app())
+        .add_systems(Startup, setup)
+        .run();
+}
@@ -6,6 +6,7 @@ use crate::{
 use bevy_ecs::prelude::*;
 use bevy_math::VectorSpace;
 use bevy_time::Time;
+use bevy_window::RequestRedraw;
 use std::{
     cell::{Cell, RefCell},
     ops::Deref,
@@ -139,3 +140,188 @@ impl<T> Clone for AnimationController<T> {
         }
     }
 }
+
+/// Keyframe of a [`Timeline`].
+#[derive(Clone, Copy, Debug)]
+pub struct Keyframe<T> {
+    /// Value to animate to.
+    pub value: T,
+
+    /// Delay before this keyframe starts, holding the previous value.
+    pub delay: Duration,
+
+    /// Duration of the animation to this keyframe's value.
+    pub duration: Duration,
+}
+
+/// Timeline of keyframes for [`use_timeline`].
+#[derive(Clone, Debug)]
+pub struct Timeline<T> {
+    keyframes: Vec<Keyframe<T>>,
+    delay: Duration,
+    is_looping: bool,
+}
+
+impl<T> Default for Timeline<T> {
+    fn default() -> Self {
+        Self {
+            keyframes: Vec::new(),
+            delay: Duration::ZERO,
+            is_looping: false,
+        }
+    }
+}
+
+impl<T> Timeline<T> {
+    /// Create a new, empty timeline.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a keyframe that animates to `value` over `duration`.
+    pub fn keyframe(self, value: T, duration: Duration) -> Self {
+        self.keyframe_with_delay(value, Duration::ZERO, duration)
+    }
+
+    /// Add a keyframe that waits for `delay`, and then animates to `value` over `duration`.
+    pub fn keyframe_with_delay(mut self, value: T, delay: Duration, duration: Duration) -> Self {
+        self.keyframes.push(Keyframe {
+            value,
+            delay,
+            duration,
+        });
+        self
+    }
+
+    /// Set the delay before this timeline starts (default: zero).
+    ///
+    /// This is only applied once, before the first loop.
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Stagger the start of this timeline by `index * interval`.
+    ///
+    /// This is useful for choreographing lists, where each item starts slightly after the last.
+    pub fn stagger(self, index: usize, interval: Duration) -> Self {
+        self.delay(interval * index as u32)
+    }
+
+    /// Enable or disable looping this timeline (default: false).
+    pub fn looping(mut self, is_looping: bool) -> Self {
+        self.is_looping = is_looping;
+        self
+    }
+}
+
+/// Use an animated value driven by a [`Timeline`] of keyframes.
+///
+/// The timeline starts playing on the first composition.
+/// `timeline` is only used on the first composition, and can't be changed after.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{animation::{use_timeline, Timeline}, prelude::*};
+/// use bevy::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Data)]
+/// struct Item {
+///     index: usize,
+/// }
+///
+/// impl Compose for Item {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Fade each item in, 50ms after the previous item.
+///         let opacity = use_timeline(
+///             &cx,
+///             || 0.,
+///             Timeline::new()
+///                 .keyframe(1., Duration::from_millis(200))
+///                 .stagger(cx.me().index, Duration::from_millis(50)),
+///         );
+///
+///         spawn((
+///             Node::default(),
+///             BackgroundColor(Color::WHITE.with_alpha(*opacity)),
+///         ))
+///     }
+/// }
+/// ```
+pub fn use_timeline<T>(
+    cx: ScopeState,
+    make_initial: impl FnOnce() -> T,
+    timeline: Timeline<T>,
+) -> UseAnimated<T>
+where
+    T: VectorSpace + Send + 'static,
+{
+    let animated = use_animated(cx, make_initial);
+
+    let mut timeline_cell = Some(timeline);
+    let timeline = use_ref(cx, || timeline_cell.take().unwrap());
+
+    use_local_task(cx, move || async move {
+        // Hold the current value for a delay by animating to itself.
+        if !timeline.delay.is_zero() {
+            animated.animate(*animated, timeline.delay).await;
+        }
+
+        loop {
+            for keyframe in &timeline.keyframes {
+                if !keyframe.delay.is_zero() {
+                    animated.animate(*animated, keyframe.delay).await;
+                }
+
+                animated.animate(keyframe.value, keyframe.duration).await;
+            }
+
+            if !timeline.is_looping || timeline.keyframes.is_empty() {
+                break;
+            }
+        }
+    });
+
+    animated
+}
+
+/// Use a function that is called on every frame with the time elapsed since the last frame.
+///
+/// While this hook is active, a redraw is requested on every frame,
+/// so animations keep running when the app is in a reactive update mode
+/// (e.g. with `WinitSettings::desktop_app()`).
+/// Redraws stop being requested once this hook is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Spinner;
+///
+/// impl Compose for Spinner {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let angle = use_mut(&cx, || 0f32);
+///
+///         use_animation_frame(&cx, move |dt| {
+///             let delta = dt.as_secs_f32() * std::f32::consts::PI;
+///             SignalMut::update(angle, move |angle| *angle = (*angle + delta) % std::f32::consts::TAU)
+///         });
+///
+///         spawn(Text::new(format!("{:.0}°", angle.to_degrees())))
+///     }
+/// }
+/// ```
+pub fn use_animation_frame<'a>(cx: ScopeState<'a>, mut f: impl FnMut(Duration) + 'a) {
+    use_world(
+        cx,
+        move |time: Res<Time>, mut request_redraw_events: EventWriter<RequestRedraw>| {
+            f(time.delta());
+            request_redraw_events.send(RequestRedraw);
+        },
+    );
+}
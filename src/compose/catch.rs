@@ -1,6 +1,8 @@
 use super::CatchContext;
-use crate::{compose::Compose, data::Data, use_provider, Scope, Signal};
-use core::mem;
+use crate::{
+    compose::Compose, data::Data, use_mut, use_provider, use_ref, Scope, Signal, SignalMut,
+};
+use core::{cell::RefCell, mem};
 
 /// Create a composable that catches errors from its children.
 /// This will catch all errors from its descendants, until another `catch` is encountered.
@@ -75,3 +77,101 @@ impl<C: Compose> Compose for Catch<'_, C> {
         unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
     }
 }
+
+/// Create an error boundary that composes a fallback when any of its descendants return an error.
+///
+/// When an error is caught, `content` is removed and the composable returned by `make_fallback`
+/// is composed in its place.
+/// The fallback stays in place until this composable is removed from the composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Price {
+///     input: String,
+/// }
+///
+/// impl Compose for Price {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let price: f32 = cx.me().input.parse().map_err(Error::new)?;
+///
+///         Ok(spawn(Text::new(format!("${price:.2}"))))
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         error_boundary(
+///             |error| spawn(Text::new(format!("Failed to load price: {error}"))),
+///             Price {
+///                 input: String::from("twelve"),
+///             },
+///         )
+///     }
+/// }
+/// ```
+pub fn error_boundary<'a, C, F>(
+    make_fallback: impl Fn(&dyn core::error::Error) -> F + 'a,
+    content: C,
+) -> ErrorBoundary<'a, C, F>
+where
+    C: Compose,
+    F: Compose,
+{
+    ErrorBoundary {
+        content,
+        make_fallback: Box::new(make_fallback),
+    }
+}
+
+type MakeFallbackFn<'a, F> = Box<dyn Fn(&dyn core::error::Error) -> F + 'a>;
+
+/// Error boundary composable.
+///
+/// See [`error_boundary`] for more.
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct ErrorBoundary<'a, C, F> {
+    /// Content of this composable.
+    content: C,
+
+    /// Function to create the fallback content.
+    make_fallback: MakeFallbackFn<'a, F>,
+}
+
+impl<C: Compose, F: Compose> Compose for ErrorBoundary<'_, C, F> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let error: &RefCell<Option<Box<dyn core::error::Error>>> =
+            use_ref(&cx, || RefCell::new(None));
+        let tick = use_mut(&cx, || ());
+
+        use_provider(&cx, move || {
+            let f: Box<dyn Fn(Box<dyn core::error::Error>) + '_> = Box::new(move |caught| {
+                *error.borrow_mut() = Some(caught);
+                SignalMut::set(tick, ());
+            });
+
+            // Safety: This function only borrows this scope, which is guaranteed to outlive its descendants.
+            let f: Box<dyn Fn(Box<dyn core::error::Error>)> = unsafe { mem::transmute(f) };
+
+            CatchContext { f }
+        });
+
+        if let Some(error) = &*error.borrow() {
+            (Some((cx.me().make_fallback)(&**error)), None)
+        } else {
+            // Safety: The content of this composable is only returned into the composition once.
+            (
+                None,
+                Some(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }),
+            )
+        }
+    }
+}
@@ -110,7 +110,7 @@ impl Compose for DynCompose<'_> {
             scope: ScopeData::default(),
             parent: Some(rt.current_key.get()),
             children: RefCell::new(Vec::new()),
-            child_idx: 0,
+            child_idx: Cell::new(0),
         }));
         state.set(Some(DynComposeState { key, data_id }));
 
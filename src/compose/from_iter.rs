@@ -1,12 +1,20 @@
 use super::{AnyCompose, Node, Runtime};
 use crate::{compose::Compose, data::Data, use_ref, Scope, ScopeData, Signal};
 use alloc::rc::Rc;
-use core::{cell::RefCell, mem};
+use core::{
+    cell::{Cell, RefCell},
+    mem,
+};
 use slotmap::DefaultKey;
 
 /// Create a composable from an iterator.
 ///
 /// `make_item` will be called for each item to produce a composable.
+///
+/// Items are tracked by their index, so removing an item will move the state of later items.
+/// In debug builds with the `tracing` feature, a warning is logged once when the number of items decreases
+/// (this can be disabled with [`FromIter::suppress_index_warning`]).
+/// For lists where items can be removed or reordered, see [`from_iter_keyed`](super::from_iter_keyed).
 pub fn from_iter<'a, I, C>(
     iter: I,
     make_item: impl Fn(Signal<'a, I::Item>) -> C + 'a,
@@ -19,6 +27,7 @@ where
     FromIter {
         iter,
         make_item: Box::new(make_item),
+        is_index_warning_enabled: true,
     }
 }
 
@@ -27,6 +36,17 @@ where
 pub struct FromIter<'a, I, Item, C> {
     iter: I,
     make_item: Box<dyn Fn(Signal<'a, Item>) -> C + 'a>,
+    is_index_warning_enabled: bool,
+}
+
+impl<I, Item, C> FromIter<'_, I, Item, C> {
+    /// Disable the debug warning for removed items (default: enabled).
+    ///
+    /// This is useful for lists where items are only removed from the end.
+    pub fn suppress_index_warning(mut self) -> Self {
+        self.is_index_warning_enabled = false;
+        self
+    }
 }
 
 unsafe impl<I, Item, C> Data for FromIter<'_, I, Item, C>
@@ -49,6 +69,20 @@ where
 
         let mut items: Vec<Option<_>> = cx.me().iter.clone().into_iter().map(Some).collect();
 
+        #[cfg(all(debug_assertions, feature = "tracing"))]
+        {
+            let is_warned = use_ref(&cx, || core::cell::Cell::new(false));
+            if cx.me().is_index_warning_enabled && !is_warned.get() && items.len() < states.len() {
+                tracing::warn!(
+                    "from_iter: items changed from {} to {}. Items are tracked by index, \
+                    so the state of removed items may be kept by other items.",
+                    states.len(),
+                    items.len()
+                );
+                is_warned.set(true);
+            }
+        }
+
         let rt = Runtime::current();
 
         if items.len() >= states.len() {
@@ -92,7 +126,7 @@ where
                     scope: ScopeData::default(),
                     parent: Some(rt.current_key.get()),
                     children: RefCell::new(Vec::new()),
-                    child_idx: idx,
+                    child_idx: Cell::new(idx),
                 }));
                 nodes
                     .get(rt.current_key.get())
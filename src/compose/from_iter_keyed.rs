@@ -0,0 +1,209 @@
+use super::{drop_node, AnyCompose, Node, Runtime};
+use crate::{compose::Compose, data::Data, use_ref, Scope, ScopeData, Signal};
+use alloc::rc::Rc;
+use core::{
+    cell::{Cell, RefCell},
+    hash::Hash,
+    mem,
+};
+use slotmap::DefaultKey;
+use std::collections::HashMap;
+
+/// Create a composable from an iterator, with items tracked by a key.
+///
+/// `make_key` will be called for each item to produce its key,
+/// and `make_item` will be called for each item to produce a composable.
+///
+/// Items are tracked by their key, so the state of each item is kept when items are reordered,
+/// and the state of removed items is dropped.
+/// New keys are composed with fresh state.
+///
+/// Keys must be unique within the iterator.
+///
+/// # Panics
+///
+/// In debug builds, this panics if two items have the same key.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Clone, Data)]
+/// struct Todo {
+///     id: u64,
+///     title: String,
+/// }
+///
+/// #[derive(Data)]
+/// struct TodoItem<'a> {
+///     todo: Signal<'a, Todo>,
+/// }
+///
+/// impl Compose for TodoItem<'_> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // This state is kept when todos are reordered.
+///         let _is_done = use_mut(&cx, || false);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct TodoList {
+///     todos: Vec<Todo>,
+/// }
+///
+/// impl Compose for TodoList {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         compose::from_iter_keyed(
+///             cx.me().todos.clone(),
+///             |todo| todo.id,
+///             |todo| TodoItem { todo },
+///         )
+///     }
+/// }
+/// ```
+pub fn from_iter_keyed<'a, I, K, C>(
+    iter: I,
+    make_key: impl Fn(&I::Item) -> K + 'a,
+    make_item: impl Fn(Signal<'a, I::Item>) -> C + 'a,
+) -> FromIterKeyed<'a, I, I::Item, K, C>
+where
+    I: IntoIterator + Clone + Data,
+    I::Item: 'static,
+    K: Hash + Eq + 'static,
+    C: Compose,
+{
+    FromIterKeyed {
+        iter,
+        make_key: Box::new(make_key),
+        make_item: Box::new(make_item),
+    }
+}
+
+/// Composable from an iterator with keyed items, created with [`from_iter_keyed`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct FromIterKeyed<'a, I, Item, K, C> {
+    iter: I,
+    make_key: Box<dyn Fn(&Item) -> K + 'a>,
+    make_item: Box<dyn Fn(Signal<'a, Item>) -> C + 'a>,
+}
+
+unsafe impl<I, Item, K, C> Data for FromIterKeyed<'_, I, Item, K, C>
+where
+    I: Data,
+    Item: 'static,
+    K: 'static,
+    C: Data,
+{
+}
+
+impl<I, Item, K, C> Compose for FromIterKeyed<'_, I, Item, K, C>
+where
+    I: IntoIterator<Item = Item> + Clone + Data,
+    Item: 'static,
+    K: Hash + Eq + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let states: &RefCell<Vec<(K, ItemState<Item>)>> = use_ref(&cx, || RefCell::new(Vec::new()));
+        let mut states = states.borrow_mut();
+
+        let rt = Runtime::current();
+
+        let make_compose = |item: &Item| {
+            let item: &Item = unsafe { mem::transmute(item) };
+            let compose = (cx.me().make_item)(Signal {
+                value: item,
+                generation: &cx.generation as _,
+            });
+            let any_compose: Box<dyn AnyCompose> = Box::new(compose);
+            let any_compose: Box<dyn AnyCompose> = unsafe { mem::transmute(any_compose) };
+            any_compose
+        };
+
+        // Previous items by key, and items with duplicate keys to remove.
+        let mut last_states = HashMap::new();
+        let mut removed_states = Vec::new();
+        for (key, state) in states.drain(..) {
+            if let Some(state) = last_states.insert(key, state) {
+                removed_states.push(state);
+            }
+        }
+
+        for (idx, item) in cx.me().iter.clone().into_iter().enumerate() {
+            let key = (cx.me().make_key)(&item);
+
+            if let Some(mut state) = last_states.remove(&key) {
+                *state.item = item;
+
+                let mut compose = make_compose(&state.item);
+                let nodes = rt.nodes.borrow();
+                let node = &nodes[state.key];
+
+                // Safety: both composables are the same type.
+                unsafe { compose.reborrow(node.compose.borrow_mut().as_ptr_mut()) };
+
+                node.child_idx.set(idx);
+
+                states.push((key, state));
+            } else {
+                let item = Box::new(item);
+                let compose = make_compose(&item);
+
+                let mut nodes = rt.nodes.borrow_mut();
+                let node_key = nodes.insert(Rc::new(Node {
+                    compose: RefCell::new(crate::composer::ComposePtr::Boxed(compose)),
+                    scope: ScopeData::default(),
+                    parent: Some(rt.current_key.get()),
+                    children: RefCell::new(Vec::new()),
+                    child_idx: Cell::new(idx),
+                }));
+
+                let child_state = &nodes[node_key].scope;
+                *child_state.contexts.borrow_mut() = cx.contexts.borrow().clone();
+                child_state
+                    .contexts
+                    .borrow_mut()
+                    .values
+                    .extend(cx.child_contexts.borrow().values.clone());
+
+                states.push((
+                    key,
+                    ItemState {
+                        item,
+                        key: node_key,
+                    },
+                ));
+            }
+        }
+
+        #[cfg(debug_assertions)]
+        {
+            let mut keys = std::collections::HashSet::new();
+            for (key, _) in states.iter() {
+                assert!(keys.insert(key), "from_iter_keyed: duplicate key");
+            }
+        }
+
+        // Drop the nodes of removed items before their items.
+        let mut nodes = rt.nodes.borrow_mut();
+        for state in removed_states.into_iter().chain(last_states.into_values()) {
+            drop_node(&mut nodes, state.key);
+        }
+
+        // Keep the children of this node in the order of the items.
+        *nodes[rt.current_key.get()].children.borrow_mut() =
+            states.iter().map(|(_, state)| state.key).collect();
+
+        drop(nodes);
+
+        for (_, state) in states.iter() {
+            rt.queue(state.key);
+        }
+    }
+}
+
+struct ItemState<T> {
+    item: Box<T>,
+    key: DefaultKey,
+}
@@ -1,7 +1,11 @@
-use super::{use_node, AnyCompose, Runtime};
-use crate::{compose::Compose, composer::ComposePtr, data::Data, use_ref, Scope};
-use alloc::borrow::Cow;
-use core::cell::RefCell;
+use super::{drop_node, use_node, AnyCompose, Node, Runtime};
+use crate::{compose::Compose, composer::ComposePtr, data::Data, use_ref, Scope, ScopeData};
+use alloc::{borrow::Cow, rc::Rc};
+use core::{
+    cell::{Cell, RefCell},
+    hash::Hash,
+};
+use slotmap::DefaultKey;
 use std::mem;
 
 /// Create a new memoized composable.
@@ -9,6 +13,10 @@ use std::mem;
 /// The content of the memoized composable is only re-composed when the dependency changes.
 ///
 /// Children of this `Memo` may still be re-composed if their state has changed.
+///
+/// Only the dependency is compared, so content that reads interior-mutable state it captures
+/// (like an `Rc<RefCell<T>>`) won't be re-composed when that state changes.
+/// To re-compose on other changes, use [`memo_always_if`].
 pub fn memo<D, C>(dependency: D, content: C) -> Memo<D, C>
 where
     D: Data + Clone + PartialEq + 'static,
@@ -67,3 +75,217 @@ where
         )
     }
 }
+
+/// Create a new composable memoized by a predicate.
+///
+/// The content is composed once, and then only re-composed when `predicate` returns `true`.
+/// The predicate is called every time this composable is composed,
+/// so it can read state that isn't tracked as a dependency (like interior-mutable state).
+///
+/// Children of this `MemoAlwaysIf` may still be re-composed if their state has changed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+/// use std::{
+///     cell::{Cell, RefCell},
+///     rc::Rc,
+/// };
+///
+/// #[derive(Data)]
+/// struct Items {
+///     items: Rc<RefCell<Vec<String>>>,
+/// }
+///
+/// impl Compose for Items {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let _ = cx.me().items.borrow().len();
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     items: Rc<RefCell<Vec<String>>>,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let items = cx.me().items.clone();
+///
+///         // Re-compose the items when their length changes.
+///         let last_len = use_ref(&cx, || Cell::new(0));
+///         let len = items.borrow().len();
+///         memo_always_if(
+///             move || last_len.replace(len) != len,
+///             Items { items },
+///         )
+///     }
+/// }
+/// ```
+pub fn memo_always_if<'a, C>(predicate: impl Fn() -> bool + 'a, content: C) -> MemoAlwaysIf<'a, C>
+where
+    C: Compose,
+{
+    MemoAlwaysIf {
+        predicate: Box::new(predicate),
+        content,
+    }
+}
+
+/// Composable memoized by a predicate.
+///
+/// See [`memo_always_if`] for more.
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct MemoAlwaysIf<'a, C> {
+    predicate: Box<dyn Fn() -> bool + 'a>,
+    content: C,
+}
+
+unsafe impl<C: Data> Data for MemoAlwaysIf<'_, C> {}
+
+impl<C> Compose for MemoAlwaysIf<'_, C>
+where
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let rt = Runtime::current();
+
+        let ptr: *const dyn AnyCompose =
+            unsafe { mem::transmute(&cx.me().content as *const dyn AnyCompose) };
+        let (key, _) = use_node(&cx, ComposePtr::Ptr(ptr), 0);
+
+        let is_composed = use_ref(&cx, || Cell::new(false));
+
+        if !is_composed.replace(true) || (cx.me().predicate)() {
+            rt.queue(key);
+        }
+    }
+
+    fn name() -> Option<Cow<'static, str>> {
+        Some(
+            C::name()
+                .map(|name| format!("MemoAlwaysIf<{}>", name).into())
+                .unwrap_or("MemoAlwaysIf".into()),
+        )
+    }
+}
+
+/// Create a new composable memoized by a key.
+///
+/// The content of the keyed composable is only re-composed when the key changes.
+/// When the key changes, the previous content is dropped and the new content is composed with fresh state
+/// (this can be used to reset the state of a composable).
+///
+/// Children of this `KeyedMemo` may still be re-composed if their state has changed.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Profile {
+///     user_id: u64,
+/// }
+///
+/// impl Compose for Profile {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // The draft is reset when the user changes.
+///         let _draft = use_mut(&cx, String::new);
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     user_id: u64,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let user_id = cx.me().user_id;
+///         keyed_memo(user_id, Profile { user_id })
+///     }
+/// }
+/// ```
+pub fn keyed_memo<K, C>(key: K, content: C) -> KeyedMemo<K, C>
+where
+    K: Hash + Eq + Clone + 'static,
+    C: Compose,
+{
+    KeyedMemo { key, content }
+}
+
+/// Composable memoized by a key.
+///
+/// See [`keyed_memo`] for more.
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct KeyedMemo<K, C> {
+    key: K,
+    content: C,
+}
+
+unsafe impl<K: 'static, C: Data> Data for KeyedMemo<K, C> {}
+
+impl<K, C> Compose for KeyedMemo<K, C>
+where
+    K: Hash + Eq + Clone + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let rt = Runtime::current();
+
+        let ptr: *const dyn AnyCompose =
+            unsafe { mem::transmute(&cx.me().content as *const dyn AnyCompose) };
+
+        let state: &RefCell<Option<(K, DefaultKey)>> = use_ref(&cx, RefCell::default);
+        let mut state = state.borrow_mut();
+
+        if let Some((last_key, node_key)) = &*state {
+            if *last_key == cx.me().key {
+                // Reborrow the pointer to the node's composable.
+                *rt.nodes.borrow()[*node_key].compose.borrow_mut() = ComposePtr::Ptr(ptr);
+                return;
+            }
+
+            drop_node(&mut rt.nodes.borrow_mut(), *node_key);
+        }
+
+        let mut nodes = rt.nodes.borrow_mut();
+        let key = nodes.insert(Rc::new(Node {
+            compose: RefCell::new(ComposePtr::Ptr(ptr)),
+            scope: ScopeData::default(),
+            parent: Some(rt.current_key.get()),
+            children: RefCell::new(Vec::new()),
+            child_idx: Cell::new(0),
+        }));
+
+        nodes
+            .get(rt.current_key.get())
+            .unwrap()
+            .children
+            .borrow_mut()
+            .push(key);
+
+        let child_state = &nodes[key].scope;
+        *child_state.contexts.borrow_mut() = cx.contexts.borrow().clone();
+        child_state
+            .contexts
+            .borrow_mut()
+            .values
+            .extend(cx.child_contexts.borrow().values.clone());
+
+        drop(nodes);
+
+        *state = Some((cx.me().key.clone(), key));
+        rt.queue(key);
+    }
+
+    fn name() -> Option<Cow<'static, str>> {
+        Some(
+            C::name()
+                .map(|name| format!("KeyedMemo<{}>", name).into())
+                .unwrap_or("KeyedMemo".into()),
+        )
+    }
+}
@@ -13,7 +13,7 @@ use slotmap::{DefaultKey, SlotMap};
 use std::{cell::Cell, fmt, rc::Rc};
 
 mod catch;
-pub use self::catch::{catch, Catch};
+pub use self::catch::{catch, error_boundary, Catch, ErrorBoundary};
 
 mod dyn_compose;
 pub use self::dyn_compose::{dyn_compose, DynCompose};
@@ -24,8 +24,14 @@ pub use self::from_fn::{from_fn, FromFn};
 mod from_iter;
 pub use self::from_iter::{from_iter, FromIter};
 
+mod from_iter_keyed;
+pub use self::from_iter_keyed::{from_iter_keyed, FromIterKeyed};
+
 mod memo;
-pub use self::memo::{memo, Memo};
+pub use self::memo::{keyed_memo, memo, memo_always_if, KeyedMemo, Memo, MemoAlwaysIf};
+
+mod show;
+pub use self::show::{show, Show};
 
 /// A composable function.
 ///
@@ -45,6 +51,41 @@ pub use self::memo::{memo, Memo};
 /// [`Memo`] is special in that it will only recompose in two cases:
 /// 1. It's provided dependencies have changed (see [`memo()`] for more)
 /// 2. Its own state has changed, which will then trigger the above parent-to-child process for its children.
+///
+/// ## Children
+/// Composables can wrap child content by storing it in a field.
+/// The `#[derive(Data)]` macro requires generic fields to implement [`Data`],
+/// so any composable can be passed as a child.
+///
+/// The content can then be returned from [`Compose::compose`] with [`Signal::map_unchecked`](crate::Signal::map_unchecked).
+/// The child's scope (and its state) is preserved across re-compositions of the wrapper.
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Card<C> {
+///     content: C,
+/// }
+///
+/// impl<C: Compose> Compose for Card<C> {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Safety: The content of this composable is only returned into the composition once.
+///         unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         Card {
+///             content: Card { content: () },
+///         }
+///     }
+/// }
+/// ```
 #[must_use = "Composables do nothing unless composed or returned from other composables."]
 pub trait Compose: Data {
     /// Compose this function.
@@ -98,7 +139,7 @@ impl<C: Compose> Compose for Option<C> {
                     scope: ScopeData::default(),
                     parent: Some(rt.current_key.get()),
                     children: RefCell::new(Vec::new()),
-                    child_idx: 0,
+                    child_idx: Cell::new(0),
                 }));
                 child_key.set(Some(key));
 
@@ -207,7 +248,7 @@ impl<C: Compose> Compose for Result<C, Error> {
                         scope: ScopeData::default(),
                         parent: Some(rt.current_key.get()),
                         children: RefCell::new(Vec::new()),
-                        child_idx: 0,
+                        child_idx: Cell::new(0),
                     }));
                     child_key.set(Some(key));
 
@@ -298,7 +339,7 @@ fn use_node(cx: ScopeState, compose_ptr: ComposePtr, child_idx: usize) -> (Defau
             scope: ScopeData::default(),
             parent: Some(rt.current_key.get()),
             children: RefCell::new(Vec::new()),
-            child_idx,
+            child_idx: Cell::new(child_idx),
         }));
 
         nodes
@@ -405,7 +446,7 @@ where
                         scope: ScopeData::default(),
                         parent: Some(rt.current_key.get()),
                         children: RefCell::new(Vec::new()),
-                        child_idx: 0,
+                        child_idx: Cell::new(0),
                     }));
                     child_key_cell.set(Some(child_key));
 
@@ -0,0 +1,59 @@
+use crate::{compose::Compose, data::Data, Scope, Signal};
+
+/// Create a composable that shows its content only when `when` is `true`.
+///
+/// When `when` changes to `false`, the content is dropped (running any cleanup from hooks like [`use_drop`](crate::use_drop)).
+/// When it changes back to `true`, the content is composed again with fresh state.
+///
+/// This is useful when branching on a condition with [`Option`] would be awkward,
+/// like when the content is built before the condition is known.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Dialog;
+///
+/// impl Compose for Dialog {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         use_drop(&cx, || println!("Dialog closed"));
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App {
+///     is_open: bool,
+/// }
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         show(cx.me().is_open, Dialog)
+///     }
+/// }
+/// ```
+pub fn show<C: Compose>(when: bool, content: C) -> Show<C> {
+    Show { when, content }
+}
+
+/// Composable that shows its content by a condition.
+///
+/// See [`show`] for more.
+#[derive(Data)]
+#[actuate(path = "crate")]
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct Show<C> {
+    when: bool,
+    content: C,
+}
+
+impl<C: Compose> Compose for Show<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        if cx.me().when {
+            Some(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+        } else {
+            None
+        }
+    }
+}
@@ -2,7 +2,8 @@ use crate::{
     compose::{AnyCompose, CatchContext, Compose},
     ScopeData,
 };
-use alloc::{collections::BTreeSet, rc::Rc, sync::Arc, task::Wake};
+use alloc::{borrow::Cow, collections::BTreeSet, rc::Rc, sync::Arc, task::Wake};
+use core::sync::atomic::{AtomicBool, Ordering as AtomicOrdering};
 use core::{
     any::TypeId,
     cell::{Cell, RefCell},
@@ -13,15 +14,111 @@ use core::{
     mem,
     pin::Pin,
     task::{Context, Poll, Waker},
+    time::Duration,
 };
 use crossbeam_queue::SegQueue;
-use slotmap::{DefaultKey, SlotMap};
+use futures::task::AtomicWaker;
+use slotmap::{DefaultKey, Key, SlotMap};
+use std::time::Instant;
 
 #[cfg(feature = "executor")]
-use tokio::sync::RwLock;
+use std::sync::{Mutex, PoisonError, RwLock, RwLockReadGuard, TryLockError};
 
 type RuntimeFuture = Pin<Box<dyn Future<Output = ()>>>;
 
+/// Lock that keeps shared tasks from running while updates are applied.
+///
+/// Updates take the write lock on the composition thread,
+/// while tasks poll for the read lock without blocking their executor's threads.
+#[cfg(feature = "executor")]
+#[derive(Default)]
+pub(crate) struct TaskLock {
+    lock: RwLock<()>,
+
+    /// Wakers of tasks waiting for an update to finish.
+    wakers: Mutex<Vec<Waker>>,
+}
+
+#[cfg(feature = "executor")]
+impl TaskLock {
+    /// Take the write lock, blocking until no tasks are running.
+    ///
+    /// Tasks waiting for this lock are woken once the returned guard is dropped.
+    pub(crate) fn write(&self) -> TaskLockWriteGuard<'_> {
+        let guard = self.lock.write().unwrap_or_else(PoisonError::into_inner);
+        TaskLockWriteGuard {
+            lock: self,
+            guard: Some(guard),
+        }
+    }
+
+    /// Poll for the read lock, registering `cx`'s waker to be woken when it's available.
+    pub(crate) fn poll_read(&self, cx: &mut Context) -> Poll<RwLockReadGuard<'_, ()>> {
+        if let Some(guard) = self.try_read() {
+            return Poll::Ready(guard);
+        }
+
+        self.wakers
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(cx.waker().clone());
+
+        // Try again in case the lock was released before the waker was registered.
+        match self.try_read() {
+            Some(guard) => Poll::Ready(guard),
+            None => Poll::Pending,
+        }
+    }
+
+    fn try_read(&self) -> Option<RwLockReadGuard<'_, ()>> {
+        match self.lock.try_read() {
+            Ok(guard) => Some(guard),
+            Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+            Err(TryLockError::WouldBlock) => None,
+        }
+    }
+}
+
+/// Write guard of a [`TaskLock`].
+#[cfg(feature = "executor")]
+pub(crate) struct TaskLockWriteGuard<'a> {
+    lock: &'a TaskLock,
+    guard: Option<std::sync::RwLockWriteGuard<'a, ()>>,
+}
+
+#[cfg(feature = "executor")]
+impl Drop for TaskLockWriteGuard<'_> {
+    fn drop(&mut self) {
+        // Release the lock before waking tasks waiting for it.
+        self.guard.take();
+
+        let wakers = mem::take(
+            &mut *self
+                .lock
+                .wakers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        for waker in wakers {
+            waker.wake();
+        }
+    }
+}
+
+type BatchedUpdate = Box<dyn FnOnce() + Send>;
+
+/// Subscriber to updates of a signal's value, created with [`Signal::subscribe`](crate::Signal::subscribe).
+pub(crate) struct Subscriber {
+    /// Pointer to the signal's value.
+    pub(crate) ptr: *const (),
+
+    /// Type ID of the signal's value.
+    pub(crate) type_id: TypeId,
+
+    /// Function called with a pointer to the value after each update.
+    pub(crate) f: Rc<dyn Fn(*const ())>,
+}
+
 pub(crate) enum ComposePtr {
     Boxed(Box<dyn AnyCompose>),
     Ptr(*const dyn AnyCompose),
@@ -70,7 +167,7 @@ pub(crate) struct Node {
     pub(crate) scope: ScopeData<'static>,
     pub(crate) parent: Option<DefaultKey>,
     pub(crate) children: RefCell<Vec<DefaultKey>>,
-    pub(crate) child_idx: usize,
+    pub(crate) child_idx: Cell<usize>,
 }
 
 /// Runtime for a [`Composer`].
@@ -85,9 +182,12 @@ pub(crate) struct Runtime {
     /// Queue for updates that mutate the composition tree.
     pub(crate) update_queue: Rc<SegQueue<Box<dyn FnMut()>>>,
 
+    /// Updates queued during the current [`Runtime::batch`], if any.
+    pub(crate) batch: Rc<RefCell<Option<Vec<BatchedUpdate>>>>,
+
     #[cfg(feature = "executor")]
     /// Update lock for shared tasks.
-    pub(crate) lock: Arc<RwLock<()>>,
+    pub(crate) lock: Arc<TaskLock>,
 
     pub(crate) waker: RefCell<Option<Waker>>,
 
@@ -98,6 +198,12 @@ pub(crate) struct Runtime {
     pub(crate) root: DefaultKey,
 
     pub(crate) pending: Rc<RefCell<BTreeSet<Pending>>>,
+
+    /// `true` while the [`Composer`] of this runtime is composing.
+    pub(crate) is_composing: Rc<Cell<bool>>,
+
+    /// Subscribers to signal updates.
+    pub(crate) subscribers: Rc<RefCell<SlotMap<DefaultKey, Subscriber>>>,
 }
 
 impl Runtime {
@@ -106,13 +212,12 @@ impl Runtime {
     /// # Panics
     /// Panics if called outside of a runtime.
     pub fn current() -> Self {
-        RUNTIME.with(|runtime| {
-            runtime
-                .borrow()
-                .as_ref()
-                .expect("Runtime::current() called outside of a runtime")
-                .clone()
-        })
+        Self::try_current().expect("Runtime::current() called outside of a runtime")
+    }
+
+    /// Get the current [`Runtime`], or `None` if called outside of a runtime.
+    pub fn try_current() -> Option<Self> {
+        RUNTIME.with(|runtime| runtime.borrow().clone())
     }
 
     /// Enter this runtime, making it available to [`Runtime::current`].
@@ -124,14 +229,29 @@ impl Runtime {
 
     /// Queue an update to run after [`Composer::compose`].
     pub fn update(&self, f: impl FnOnce() + Send + 'static) {
+        if let Some(batch) = &mut *self.batch.borrow_mut() {
+            batch.push(Box::new(f));
+            return;
+        }
+
         let mut f_cell = Some(f);
 
         #[cfg(feature = "executor")]
         let lock = self.lock.clone();
 
+        #[cfg(feature = "tracing")]
+        let key = {
+            let key = self.current_key.get();
+            tracing::debug!(?key, name = ?self.node_name(key), "Push update");
+            key
+        };
+
         self.update_queue.push(Box::new(move || {
             #[cfg(feature = "executor")]
-            let _guard = lock.blocking_write();
+            let _guard = lock.write();
+
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?key, "Pop update");
 
             let f = f_cell.take().unwrap();
             f()
@@ -142,15 +262,63 @@ impl Runtime {
         }
     }
 
+    /// Run `f`, coalescing every update it queues into a single update.
+    ///
+    /// If `f` panics, the updates it queued before panicking are still applied.
+    pub fn batch<R>(&self, f: impl FnOnce() -> R) -> R {
+        // Nested batches are part of the outer batch.
+        if self.batch.borrow().is_some() {
+            return f();
+        }
+
+        *self.batch.borrow_mut() = Some(Vec::new());
+
+        // Flush the batch when `f` returns or unwinds, so later updates aren't queued to a finished batch.
+        struct BatchGuard<'a>(&'a Runtime);
+
+        impl Drop for BatchGuard<'_> {
+            fn drop(&mut self) {
+                let updates = self.0.batch.borrow_mut().take().unwrap_or_default();
+
+                if !updates.is_empty() {
+                    self.0.update(move || {
+                        for update in updates {
+                            update();
+                        }
+                    });
+                }
+            }
+        }
+
+        let _guard = BatchGuard(self);
+        f()
+    }
+
+    /// Call every subscriber to the value at `ptr` of type `type_id`.
+    pub(crate) fn notify(&self, ptr: *const (), type_id: TypeId) {
+        // Collect the subscribers first, as they may add or remove subscriptions.
+        let fns: Vec<_> = self
+            .subscribers
+            .borrow()
+            .values()
+            .filter(|subscriber| subscriber.ptr == ptr && subscriber.type_id == type_id)
+            .map(|subscriber| subscriber.f.clone())
+            .collect();
+
+        for f in fns {
+            f(ptr);
+        }
+    }
+
     pub fn pending(&self, key: DefaultKey) -> Pending {
         let nodes = self.nodes.borrow();
         let node = nodes[key].clone();
 
-        let mut indices = vec![node.child_idx];
+        let mut indices = vec![node.child_idx.get()];
         let mut parent = node.parent;
 
         while let Some(key) = parent {
-            indices.push(nodes.get(key).unwrap().child_idx);
+            indices.push(nodes.get(key).unwrap().child_idx.get());
             parent = nodes.get(key).unwrap().parent;
         }
 
@@ -161,8 +329,25 @@ impl Runtime {
 
     pub fn queue(&self, key: DefaultKey) {
         let pending = self.pending(key);
+
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            ?key,
+            name = ?self.node_name(key),
+            indices = ?pending.indices,
+            "Push pending"
+        );
+
         self.pending.borrow_mut().insert(pending);
     }
+
+    /// Get the name of the composable at `key`, if it exists and has a name.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn node_name(&self, key: DefaultKey) -> Option<std::borrow::Cow<'static, str>> {
+        let node = self.nodes.borrow().get(key)?.clone();
+        let compose = node.compose.try_borrow().ok()?;
+        compose.name()
+    }
 }
 
 thread_local! {
@@ -177,6 +362,9 @@ struct TaskWaker {
 
 impl Wake for TaskWaker {
     fn wake(self: Arc<Self>) {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(key = ?self.key, "Push task");
+
         self.queue.push(self.key);
         if let Some(waker) = self.waker.as_ref() {
             waker.wake_by_ref();
@@ -200,6 +388,16 @@ impl PartialEq for TryComposeError {
     }
 }
 
+/// Outcome of [`Composer::compose_with_budget`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ComposeBudget {
+    /// Every pending composable was composed.
+    Complete,
+
+    /// The budget was exhausted before every pending composable was composed.
+    Exhausted,
+}
+
 #[derive(Clone, PartialEq, Eq)]
 pub(crate) struct Pending {
     pub(crate) key: DefaultKey,
@@ -263,6 +461,8 @@ pub struct Composer {
     rt: Runtime,
     task_queue: Arc<SegQueue<DefaultKey>>,
     update_queue: Rc<SegQueue<Box<dyn FnMut()>>>,
+    error_cell: Rc<Cell<Option<Box<dyn Error>>>>,
+    handle: CompositionHandle,
     is_initial: bool,
 }
 
@@ -270,7 +470,7 @@ impl Composer {
     /// Create a new [`Composer`] with the given content, updater, and task executor.
     pub fn new(content: impl Compose + 'static) -> Self {
         #[cfg(feature = "executor")]
-        let lock = Arc::new(RwLock::new(()));
+        let lock = Arc::new(TaskLock::default());
 
         let task_queue = Arc::new(SegQueue::new());
         let update_queue = Rc::new(SegQueue::new());
@@ -281,7 +481,7 @@ impl Composer {
             scope: ScopeData::default(),
             parent: None,
             children: RefCell::new(Vec::new()),
-            child_idx: 0,
+            child_idx: Cell::new(0),
         }));
 
         Self {
@@ -289,6 +489,7 @@ impl Composer {
                 tasks: Rc::new(RefCell::new(SlotMap::new())),
                 task_queue: task_queue.clone(),
                 update_queue: update_queue.clone(),
+                batch: Rc::new(RefCell::new(None)),
                 waker: RefCell::new(None),
                 #[cfg(feature = "executor")]
                 lock,
@@ -296,14 +497,161 @@ impl Composer {
                 current_key: Rc::new(Cell::new(root_key)),
                 root: root_key,
                 pending: Rc::new(RefCell::new(BTreeSet::new())),
+                is_composing: Rc::new(Cell::new(false)),
+                subscribers: Rc::new(RefCell::new(SlotMap::new())),
             },
             task_queue,
             update_queue,
+            error_cell: Rc::new(Cell::new(None)),
+            handle: CompositionHandle::default(),
+            is_initial: true,
+        }
+    }
+
+    /// Create a new [`Composer`] with the given content, sharing the runtime of `composer`.
+    ///
+    /// Composers that share a runtime share their local tasks, update queue, and update lock,
+    /// so tasks and updates from one composer can coordinate with another
+    /// (e.g. a separate composer for overlays on top of an app's main content).
+    /// The content of each composer is its own root in the runtime, and is dropped with its composer,
+    /// so composers sharing a runtime can be dropped in any order.
+    ///
+    /// Updates are applied by whichever composer is composed next, so every composer sharing a runtime
+    /// should be polled for its content to stay up to date.
+    /// Cancelling one of these composers with a [`CompositionHandle`] drops the local tasks of every composer in the runtime.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct App;
+    ///
+    /// impl Compose for App {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct Overlay;
+    ///
+    /// impl Compose for Overlay {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut app = Composer::new(App);
+    /// let mut overlay = Composer::new_with_runtime(Overlay, &app);
+    ///
+    /// app.try_compose().unwrap();
+    /// overlay.try_compose().unwrap();
+    ///
+    /// assert_eq!(format!("{:?}", app), "Composer(App)");
+    /// assert_eq!(format!("{:?}", overlay), "Composer(Overlay)");
+    /// ```
+    pub fn new_with_runtime(content: impl Compose + 'static, composer: &Composer) -> Self {
+        let mut rt = composer.rt.clone();
+        *rt.waker.get_mut() = None;
+
+        // Each root gets its own index, so pending composables of different roots are kept in order.
+        let mut nodes = rt.nodes.borrow_mut();
+        let root_idx = nodes
+            .values()
+            .filter(|node| node.parent.is_none())
+            .map(|node| node.child_idx.get() + 1)
+            .max()
+            .unwrap_or(0);
+        let root_key = nodes.insert(Rc::new(Node {
+            compose: RefCell::new(ComposePtr::Boxed(Box::new(content))),
+            scope: ScopeData::default(),
+            parent: None,
+            children: RefCell::new(Vec::new()),
+            child_idx: Cell::new(root_idx),
+        }));
+        drop(nodes);
+
+        rt.root = root_key;
+
+        Self {
+            task_queue: rt.task_queue.clone(),
+            update_queue: rt.update_queue.clone(),
+            rt,
+            error_cell: Rc::new(Cell::new(None)),
+            handle: CompositionHandle::default(),
             is_initial: true,
         }
     }
 
+    /// Replace the root content of this composer.
+    ///
+    /// If `content` is the same type as the current root content,
+    /// the root is re-composed with `content` and the state of the root and its children
+    /// is kept wherever the structure of the composition is unchanged.
+    /// Otherwise, the current composition is dropped and `content` is composed from scratch.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct A {
+    ///     n: i32,
+    /// }
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A { n: 0 });
+    /// composer.try_compose().unwrap();
+    ///
+    /// composer.set_root(A { n: 1 });
+    /// composer.try_compose().unwrap();
+    /// ```
+    pub fn set_root(&mut self, content: impl Compose + 'static) {
+        let mut content: Box<dyn AnyCompose> = Box::new(content);
+        let root = self.rt.nodes.borrow()[self.rt.root].clone();
+
+        if content.data_id() == root.compose.borrow().data_id() {
+            // Safety: both composables are the same type.
+            unsafe { content.reborrow(root.compose.borrow_mut().as_ptr_mut()) };
+
+            if !self.is_initial {
+                self.rt.queue(self.rt.root);
+            }
+            return;
+        }
+
+        let root_idx = root.child_idx.get();
+
+        self.rt.enter();
+        drop_recursive(&self.rt, self.rt.root, root);
+
+        // Keep the pending composables of other roots sharing this runtime.
+        self.rt
+            .pending
+            .borrow_mut()
+            .retain(|pending| pending.indices.first() != Some(&root_idx));
+
+        let root_key = self.rt.nodes.borrow_mut().insert(Rc::new(Node {
+            compose: RefCell::new(ComposePtr::Boxed(content)),
+            scope: ScopeData::default(),
+            parent: None,
+            children: RefCell::new(Vec::new()),
+            child_idx: Cell::new(root_idx),
+        }));
+        self.rt.root = root_key;
+        self.rt.current_key.set(root_key);
+        self.is_initial = true;
+    }
+
     /// Try to immediately compose the content in this composer.
+    ///
+    /// If a composable returns an error that isn't caught by a parent, this returns early with that error.
+    /// The composition is left intact, so calling this again continues with the remaining pending composables,
+    /// and a failed composable can be retried by re-queueing it (e.g. by setting one of its signals).
+    ///
+    /// # Panics
+    ///
+    /// Panics if called while this composer is already composing (e.g. from inside one of its composables).
     pub fn try_compose(&mut self) -> Result<(), TryComposeError> {
         let mut is_pending = true;
 
@@ -320,8 +668,96 @@ impl Composer {
         }
     }
 
+    /// Compose the content in this composer until all pending composables are composed, or `budget` is exhausted.
+    ///
+    /// This returns [`ComposeBudget::Exhausted`] if the budget was exhausted before every pending composable was composed.
+    /// The remaining composables are kept in order, and are composed on the next call
+    /// (e.g. after the caller has rendered the current frame).
+    ///
+    /// At least one composable is composed on each call, so progress is always made.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::{ComposeBudget, Composer};
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Data)]
+    /// struct A;
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A);
+    ///
+    /// assert_eq!(
+    ///     composer.compose_with_budget(Duration::from_millis(8)).unwrap(),
+    ///     ComposeBudget::Complete
+    /// );
+    /// ```
+    pub fn compose_with_budget(
+        &mut self,
+        budget: Duration,
+    ) -> Result<ComposeBudget, Box<dyn Error>> {
+        let start = Instant::now();
+
+        while let Some(res) = self.next() {
+            res?;
+
+            if start.elapsed() >= budget && !self.rt.pending.borrow().is_empty() {
+                return Ok(ComposeBudget::Exhausted);
+            }
+        }
+
+        Ok(ComposeBudget::Complete)
+    }
+
+    /// Compose a single frame of the content in this composer, returning the number of composables that were composed.
+    ///
+    /// This composes every pending composable and then applies any queued updates and ready tasks,
+    /// like [`Composer::try_compose`].
+    /// Unlike [`Composer::try_compose`], an idle frame is not an error,
+    /// which makes this useful for stepping through frames in headless tests.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct A;
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A);
+    ///
+    /// assert_eq!(composer.force_frame().unwrap(), 1);
+    /// assert_eq!(composer.force_frame().unwrap(), 0);
+    /// ```
+    pub fn force_frame(&mut self) -> Result<usize, Box<dyn Error>> {
+        let mut count = 0;
+
+        for res in self.by_ref() {
+            res?;
+
+            count += 1;
+        }
+
+        Ok(count)
+    }
+
     /// Poll a composition of the content in this composer.
+    ///
+    /// If this composition was cancelled with a [`CompositionHandle`],
+    /// this drops any local tasks and returns `Poll::Ready(Ok(()))` without composing.
     pub fn poll_compose(&mut self, cx: &mut Context) -> Poll<Result<(), Box<dyn Error>>> {
+        self.handle.inner.waker.register(cx.waker());
+        if self.handle.is_cancelled() {
+            self.drop_tasks();
+            return Poll::Ready(Ok(()));
+        }
+
         *self.rt.waker.borrow_mut() = Some(cx.waker().clone());
 
         match self.try_compose() {
@@ -335,6 +771,251 @@ impl Composer {
     pub async fn compose(&mut self) -> Result<(), Box<dyn Error>> {
         futures::future::poll_fn(|cx| self.poll_compose(cx)).await
     }
+
+    /// Get a handle to cancel the composition of this composer from another task.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct A;
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A);
+    /// let handle = composer.handle();
+    ///
+    /// std::thread::spawn(move || handle.cancel()).join().unwrap();
+    ///
+    /// futures::executor::block_on(composer.compose()).unwrap();
+    /// ```
+    pub fn handle(&self) -> CompositionHandle {
+        self.handle.clone()
+    }
+
+    /// Drop every local task of this composer.
+    fn drop_tasks(&mut self) {
+        // Tasks are dropped after the borrow ends, as dropping a task may access the runtime.
+        let tasks = mem::take(&mut *self.rt.tasks.borrow_mut());
+        drop(tasks);
+
+        while self.task_queue.pop().is_some() {}
+    }
+
+    /// Take a snapshot of the composition tree.
+    ///
+    /// Like this composer's [`Debug`](fmt::Debug) output, the snapshot only contains named composables
+    /// (unnamed composables like tuples are flattened into their parents).
+    /// With the `serde` feature enabled, snapshots can be serialized for debugging tools.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct A;
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         (B, B)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct B;
+    ///
+    /// impl Compose for B {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A);
+    /// composer.try_compose().unwrap();
+    ///
+    /// let snapshot = composer.snapshot();
+    /// assert_eq!(snapshot.nodes[0].name, "A");
+    /// assert_eq!(snapshot.nodes[0].children.len(), 2);
+    /// ```
+    pub fn snapshot(&self) -> TreeSnapshot {
+        let nodes = self.rt.nodes.borrow();
+        let pending = self.rt.pending.borrow();
+
+        TreeSnapshot {
+            nodes: snapshot_nodes(&nodes, &pending, &named_nodes(&nodes, self.rt.root)),
+        }
+    }
+
+    /// Get the name of the root composable of this composer, if it has one.
+    pub fn root_name(&self) -> Option<Cow<'static, str>> {
+        self.rt.nodes.borrow()[self.rt.root].compose.borrow().name()
+    }
+
+    /// Get information about the node at a path of child indices, starting with the root's index.
+    ///
+    /// Unlike [`Composer::snapshot`], this includes unnamed nodes (like tuples),
+    /// so each index in `path` is the position of a node in its parent's children.
+    /// The root of a composer is at index `0`
+    /// (or a later index when it shares a runtime, see [`Composer::new_with_runtime`]).
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use actuate::composer::Composer;
+    ///
+    /// #[derive(Data)]
+    /// struct A;
+    ///
+    /// impl Compose for A {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         (B, B)
+    ///     }
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct B;
+    ///
+    /// impl Compose for B {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {}
+    /// }
+    ///
+    /// let mut composer = Composer::new(A);
+    /// composer.try_compose().unwrap();
+    ///
+    /// assert_eq!(composer.root_name().as_deref(), Some("A"));
+    ///
+    /// // The root `A` is at `[0]`, its tuple is at `[0, 0]`, and the second `B` is at `[0, 0, 1]`.
+    /// let info = composer.node_at(&[0, 0, 1]).unwrap();
+    /// assert_eq!(info.name.as_deref(), Some("B"));
+    /// assert_eq!(info.compose_count, 1);
+    /// ```
+    pub fn node_at(&self, path: &[usize]) -> Option<NodeInfo> {
+        let nodes = self.rt.nodes.borrow();
+
+        let (first, rest) = path.split_first()?;
+        let mut key = self.rt.root;
+        if nodes[key].child_idx.get() != *first {
+            return None;
+        }
+
+        for idx in rest {
+            key = nodes[key]
+                .children
+                .borrow()
+                .iter()
+                .copied()
+                .find(|child_key| nodes[*child_key].child_idx.get() == *idx)?;
+        }
+
+        let node = &nodes[key];
+        let info = NodeInfo {
+            name: node.compose.borrow().name().map(|name| name.to_string()),
+            is_pending: self
+                .rt
+                .pending
+                .borrow()
+                .iter()
+                .any(|pending| pending.key == key),
+            compose_count: node.scope.generation.get(),
+            child_count: node.children.borrow().len(),
+        };
+        Some(info)
+    }
+}
+
+/// Information about a node in a [`Composer`].
+///
+/// For more see [`Composer::node_at`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NodeInfo {
+    /// Name of this node's composable, if it has one.
+    pub name: Option<String>,
+
+    /// `true` if this node is pending re-composition.
+    pub is_pending: bool,
+
+    /// Number of times this node has been composed.
+    pub compose_count: u64,
+
+    /// Number of child nodes.
+    pub child_count: usize,
+}
+
+/// Handle to cancel the composition of a [`Composer`].
+///
+/// For more see [`Composer::handle`].
+#[derive(Clone, Debug, Default)]
+pub struct CompositionHandle {
+    inner: Arc<HandleInner>,
+}
+
+#[derive(Debug, Default)]
+struct HandleInner {
+    is_cancelled: AtomicBool,
+    waker: AtomicWaker,
+}
+
+impl CompositionHandle {
+    /// Cancel the composition, waking the composer if it's waiting for updates.
+    ///
+    /// The next poll of [`Composer::poll_compose`] (or [`Composer::compose`]) drops any pending local tasks
+    /// and returns `Poll::Ready(Ok(()))`, as do all polls after it.
+    pub fn cancel(&self) {
+        self.inner.is_cancelled.store(true, AtomicOrdering::SeqCst);
+        self.inner.waker.wake();
+    }
+
+    /// Returns `true` if the composition was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.is_cancelled.load(AtomicOrdering::SeqCst)
+    }
+}
+
+/// Snapshot of a composition tree.
+///
+/// For more see [`Composer::snapshot`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeSnapshot {
+    /// Top-level nodes of the composition.
+    pub nodes: Vec<NodeSnapshot>,
+}
+
+/// Snapshot of a node in a [`TreeSnapshot`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NodeSnapshot {
+    /// Name of this node's composable.
+    pub name: String,
+
+    /// Key of this node, unique for the lifetime of the node.
+    pub key: u64,
+
+    /// Index of this node in its parent's children.
+    pub child_idx: usize,
+
+    /// `true` if this node is pending re-composition.
+    pub is_pending: bool,
+
+    /// Named child nodes.
+    pub children: Vec<NodeSnapshot>,
+}
+
+fn snapshot_nodes(
+    nodes: &SlotMap<DefaultKey, Rc<Node>>,
+    pending: &BTreeSet<Pending>,
+    named: &[(DefaultKey, Cow<'static, str>)],
+) -> Vec<NodeSnapshot> {
+    named
+        .iter()
+        .map(|(key, name)| NodeSnapshot {
+            name: name.to_string(),
+            key: key.data().as_ffi(),
+            child_idx: nodes[*key].child_idx.get(),
+            is_pending: pending.iter().any(|pending| pending.key == *key),
+            children: snapshot_nodes(nodes, pending, &named_children(nodes, *key)),
+        })
+        .collect()
 }
 
 impl Drop for Composer {
@@ -358,10 +1039,12 @@ impl Iterator for Composer {
     type Item = Result<(), Box<dyn Error>>;
 
     fn next(&mut self) -> Option<Self::Item> {
+        let _guard = ComposingGuard::new(&self.rt.is_composing);
+
         self.rt.enter();
 
-        let error_cell = Rc::new(Cell::new(None));
-        let error_cell_handle = error_cell.clone();
+        // This cell is shared by every composition, as descendants keep the context they were created with.
+        let error_cell_handle = self.error_cell.clone();
 
         let root = self.rt.nodes.borrow().get(self.rt.root).unwrap().clone();
         root.scope.contexts.borrow_mut().values.insert(
@@ -374,14 +1057,39 @@ impl Iterator for Composer {
         if !self.is_initial {
             let key_cell = self.rt.pending.borrow_mut().pop_first();
             if let Some(pending) = key_cell {
-                self.rt.current_key.set(pending.key);
+                // Skip nodes that were removed after being queued.
+                let Some(node) = self.rt.nodes.borrow().get(pending.key).cloned() else {
+                    return Some(Ok(()));
+                };
+
+                #[cfg(feature = "tracing")]
+                tracing::debug!(
+                    key = ?pending.key,
+                    name = ?self.rt.node_name(pending.key),
+                    indices = ?pending.indices,
+                    "Pop pending"
+                );
+
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!(
+                    "compose",
+                    name = ?self.rt.node_name(pending.key),
+                    indices = ?pending.indices,
+                )
+                .entered();
 
-                let node = self.rt.nodes.borrow().get(pending.key).unwrap().clone();
+                self.rt.current_key.set(pending.key);
 
                 // Safety: `self.compose` is guaranteed to live as long as `self.scope_state`.
                 unsafe { node.compose.borrow().any_compose(&node.scope) };
             } else {
+                #[cfg(feature = "tracing")]
+                let _span = tracing::debug_span!("poll_tasks").entered();
+
                 while let Some(key) = self.task_queue.pop() {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(?key, "Pop task");
+
                     let waker = Waker::from(Arc::new(TaskWaker {
                         key,
                         waker: self.rt.waker.borrow().clone(),
@@ -390,8 +1098,15 @@ impl Iterator for Composer {
                     let mut cx = Context::from_waker(&waker);
 
                     let mut tasks = self.rt.tasks.borrow_mut();
-                    let task = tasks.get_mut(key).unwrap();
-                    let _ = task.as_mut().poll(&mut cx);
+
+                    // Skip tasks that were removed after being woken.
+                    let Some(task) = tasks.get_mut(key) else {
+                        continue;
+                    };
+
+                    if task.as_mut().poll(&mut cx).is_ready() {
+                        tasks.remove(key);
+                    }
                 }
 
                 while let Some(mut update) = self.update_queue.pop() {
@@ -403,13 +1118,44 @@ impl Iterator for Composer {
         } else {
             self.is_initial = false;
 
+            #[cfg(feature = "tracing")]
+            let _span = tracing::debug_span!(
+                "compose",
+                name = ?self.rt.node_name(self.rt.root),
+                indices = ?self.rt.pending(self.rt.root).indices,
+            )
+            .entered();
+
             self.rt.current_key.set(self.rt.root);
 
             // Safety: `self.compose` is guaranteed to live as long as `self.scope_state`.
             unsafe { root.compose.borrow().any_compose(&root.scope) };
         }
 
-        Some(error_cell.take().map(Err).unwrap_or(Ok(())))
+        Some(self.error_cell.take().map(Err).unwrap_or(Ok(())))
+    }
+}
+
+/// Guard to mark a [`Composer`] as composing, reset on drop.
+struct ComposingGuard<'a> {
+    is_composing: &'a Cell<bool>,
+}
+
+impl<'a> ComposingGuard<'a> {
+    fn new(is_composing: &'a Cell<bool>) -> Self {
+        assert!(
+            !is_composing.replace(true),
+            "Composer is already composing. \
+            A composer can't be composed from inside one of its own composables."
+        );
+
+        Self { is_composing }
+    }
+}
+
+impl Drop for ComposingGuard<'_> {
+    fn drop(&mut self) {
+        self.is_composing.set(false);
     }
 }
 
@@ -417,7 +1163,14 @@ impl fmt::Debug for Composer {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut dbg_tuple = f.debug_tuple("Composer");
 
-        dbg_composer(&mut dbg_tuple, &self.rt.nodes.borrow(), self.rt.root);
+        let nodes = self.rt.nodes.borrow();
+        for (key, name) in named_nodes(&nodes, self.rt.root) {
+            dbg_tuple.field(&Field {
+                name: &name,
+                nodes: &nodes,
+                key,
+            });
+        }
 
         dbg_tuple.finish()
     }
@@ -426,49 +1179,63 @@ impl fmt::Debug for Composer {
 struct Field<'a> {
     name: &'a str,
     nodes: &'a SlotMap<DefaultKey, Rc<Node>>,
-    children: &'a [DefaultKey],
+    key: DefaultKey,
 }
 
 impl fmt::Debug for Field<'_> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         let mut dbg_tuple = f.debug_tuple(self.name);
 
-        for child_key in self.children {
-            dbg_composer(&mut dbg_tuple, self.nodes, *child_key);
+        for (key, name) in named_children(self.nodes, self.key) {
+            dbg_tuple.field(&Field {
+                name: &name,
+                nodes: self.nodes,
+                key,
+            });
         }
 
         dbg_tuple.finish()
     }
 }
 
-fn dbg_composer(
-    dbg_tuple: &mut fmt::DebugTuple,
+/// Get the closest named nodes at or below `key`.
+fn named_nodes(
     nodes: &SlotMap<DefaultKey, Rc<Node>>,
     key: DefaultKey,
-) {
+) -> Vec<(DefaultKey, Cow<'static, str>)> {
     let node = &nodes[key];
     if let Some(name) = node.compose.borrow().name() {
-        dbg_tuple.field(&Field {
-            name: &name,
-            nodes,
-            children: &node.children.borrow(),
-        });
+        vec![(key, name)]
     } else {
-        for child_key in &*node.children.borrow() {
-            dbg_composer(dbg_tuple, nodes, *child_key);
-        }
+        named_children(nodes, key)
     }
 }
 
+/// Get the closest named nodes below `key`.
+fn named_children(
+    nodes: &SlotMap<DefaultKey, Rc<Node>>,
+    key: DefaultKey,
+) -> Vec<(DefaultKey, Cow<'static, str>)> {
+    nodes[key]
+        .children
+        .borrow()
+        .iter()
+        .flat_map(|child_key| named_nodes(nodes, *child_key))
+        .collect()
+}
+
 #[cfg(all(test, feature = "rt"))]
 mod tests {
     use crate::{
-        composer::{Composer, TryComposeError},
+        composer::{ComposeBudget, Composer, Runtime, TryComposeError},
         prelude::*,
+        use_history_with_capacity,
     };
     use std::{
         cell::{Cell, RefCell},
         rc::Rc,
+        task::{Context, Poll},
+        time::Duration,
     };
 
     #[derive(Data)]
@@ -644,24 +1411,1046 @@ mod tests {
     }
 
     #[test]
-    fn it_composes_memo() {
+    fn it_forces_frames() {
         #[derive(Data)]
         #[actuate(path = "crate")]
-        struct B {
-            x: Rc<RefCell<i32>>,
+        struct Counter {
+            values: Rc<RefCell<Vec<i32>>>,
         }
 
-        impl Compose for B {
+        impl Compose for Counter {
             fn compose(cx: Scope<Self>) -> impl Compose {
-                *cx.me().x.borrow_mut() += 1;
+                let (count, set_count) = use_state(&cx, || 0);
+                cx.me().values.borrow_mut().push(count);
+
+                if count < 2 {
+                    set_count.update(|x| *x += 1);
+                }
             }
         }
 
-        #[derive(Data)]
-        #[actuate(path = "crate")]
-        struct A {
-            x: Rc<RefCell<i32>>,
-        }
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(Counter {
+            values: values.clone(),
+        });
+
+        for frame in 0..3 {
+            assert_eq!(composer.force_frame().unwrap(), 1);
+            assert_eq!(values.borrow().last(), Some(&frame));
+        }
+
+        // Once the count stops changing, a frame composes nothing instead of returning an error.
+        assert_eq!(composer.force_frame().unwrap(), 0);
+        assert_eq!(*values.borrow(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn it_composes_children() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Child {
+            x: Rc<Cell<i32>>,
+        }
+
+        impl Compose for Child {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let count = use_ref(&cx, || Cell::new(0));
+                count.set(count.get() + 1);
+
+                cx.me().x.set(count.get());
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Wrap<C> {
+            content: C,
+        }
+
+        impl<C: Compose> Compose for Wrap<C> {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                // Update this parent once after it's first composed.
+                let (is_updated, set_is_updated) = use_state(&cx, || false);
+                if !is_updated {
+                    set_is_updated.set(true);
+                }
+
+                unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+            }
+        }
+
+        let x = Rc::new(Cell::new(0));
+        let mut composer = Composer::new(Wrap {
+            content: Child { x: x.clone() },
+        });
+
+        composer.try_compose().unwrap();
+        assert_eq!(x.get(), 1);
+
+        // The child's state is preserved across re-compositions of its parent.
+        composer.try_compose().unwrap();
+        assert_eq!(x.get(), 2);
+    }
+
+    #[test]
+    fn it_sets_state() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Counter {
+            values: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for Counter {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let (count, set_count) = use_state(&cx, || 0);
+                cx.me().values.borrow_mut().push(count);
+
+                if count < 2 {
+                    set_count.update(|x| *x += 1);
+                }
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(Counter {
+            values: values.clone(),
+        });
+        for _ in 0..4 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(*values.borrow(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn it_uses_reducer() {
+        fn assert_send_sync<T: Send + Sync>(_: T) {}
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Counter {
+            values: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for Counter {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let count = use_reducer(&cx, || 0, |count: &i32, step: &i32| count + step);
+                assert_send_sync(count);
+                cx.me().values.borrow_mut().push(*count);
+
+                if *count < 4 {
+                    UseReducer::dispatch(count, 2);
+                }
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(Counter {
+            values: values.clone(),
+        });
+        for _ in 0..4 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(*values.borrow(), [0, 2, 4]);
+    }
+
+    #[test]
+    fn it_cleans_up_effects() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Effect {
+            dependency: i32,
+            log: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl Compose for Effect {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let log = cx.me().log.clone();
+                use_effect_with_cleanup(&cx, cx.me().dependency, move |dependency| {
+                    let dependency = *dependency;
+                    log.borrow_mut().push(format!("run {dependency}"));
+
+                    move || log.borrow_mut().push(format!("cleanup {dependency}"))
+                });
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct App {
+            log: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl Compose for App {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 5 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                if *step < 4 {
+                    Some(Effect {
+                        dependency: *step / 2,
+                        log: cx.me().log.clone(),
+                    })
+                } else {
+                    None
+                }
+            }
+        }
+
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(App { log: log.clone() });
+        for _ in 0..6 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(*log.borrow(), ["run 0", "cleanup 0", "run 1", "cleanup 1"]);
+    }
+
+    #[test]
+    fn it_uses_memo() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Doubled {
+            calls: Rc<Cell<usize>>,
+            values: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for Doubled {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 3 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                let calls = cx.me().calls.clone();
+                let doubled = use_memo(&cx, *step / 2, move || {
+                    calls.set(calls.get() + 1);
+                    (*step / 2) * 2
+                });
+                cx.me().values.borrow_mut().push(*doubled);
+            }
+        }
+
+        let calls = Rc::new(Cell::new(0));
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(Doubled {
+            calls: calls.clone(),
+            values: values.clone(),
+        });
+        for _ in 0..5 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(*values.borrow(), [0, 0, 2, 2]);
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn it_uses_previous_value() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            values: Rc<RefCell<Vec<Option<i32>>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 2 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                let last = use_previous(&cx, *step);
+                cx.me().values.borrow_mut().push(last);
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            values: values.clone(),
+        });
+        for _ in 0..3 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(*values.borrow(), [None, Some(0), Some(1)]);
+    }
+
+    #[test]
+    fn it_notifies_subscribers() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            values: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let count = use_mut(&cx, || 0);
+                if *count < 2 {
+                    SignalMut::update(count, |x| *x += 1);
+                }
+
+                let values = cx.me().values.clone();
+                use_ref(&cx, || {
+                    Signal::subscribe(SignalMut::as_ref(count), move |count| {
+                        values.borrow_mut().push(*count)
+                    })
+                });
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            values: values.clone(),
+        });
+        for _ in 0..4 {
+            let _ = composer.try_compose();
+        }
+        assert_eq!(*values.borrow(), [1, 2]);
+
+        // Subscriptions are removed with their scope.
+        drop(composer);
+        assert!(Runtime::current().subscribers.borrow().is_empty());
+    }
+
+    #[test]
+    fn it_sets_root() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            n: i32,
+            values: Rc<RefCell<Vec<(i32, i32)>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let count = use_ref(&cx, || Cell::new(0));
+                count.set(count.get() + 1);
+
+                cx.me().values.borrow_mut().push((cx.me().n, count.get()));
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B;
+
+        impl Compose for B {
+            fn compose(_cx: Scope<Self>) -> impl Compose {}
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            n: 1,
+            values: values.clone(),
+        });
+        composer.force_frame().unwrap();
+
+        // The same root type keeps its state.
+        composer.set_root(A {
+            n: 2,
+            values: values.clone(),
+        });
+        composer.force_frame().unwrap();
+
+        // A different root type resets the composition.
+        composer.set_root(B);
+        composer.force_frame().unwrap();
+        composer.set_root(A {
+            n: 3,
+            values: values.clone(),
+        });
+        composer.force_frame().unwrap();
+
+        assert_eq!(*values.borrow(), [(1, 1), (2, 2), (3, 1)]);
+    }
+
+    #[test]
+    fn it_composes_keyed_items() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            items: Vec<u32>,
+            inits: Rc<RefCell<Vec<u32>>>,
+            drops: Rc<RefCell<Vec<u32>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                compose::from_iter_keyed(
+                    cx.me().items.clone(),
+                    |item| *item,
+                    move |item| B {
+                        item,
+                        inits: cx.me().inits.clone(),
+                        drops: cx.me().drops.clone(),
+                    },
+                )
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B<'a> {
+            item: Signal<'a, u32>,
+            inits: Rc<RefCell<Vec<u32>>>,
+            drops: Rc<RefCell<Vec<u32>>>,
+        }
+
+        impl Compose for B<'_> {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let item = *use_ref(&cx, || {
+                    cx.me().inits.borrow_mut().push(*cx.me().item);
+                    *cx.me().item
+                });
+
+                let drops = cx.me().drops.clone();
+                use_drop(&cx, move || drops.borrow_mut().push(item));
+            }
+        }
+
+        let inits = Rc::new(RefCell::new(Vec::new()));
+        let drops = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            items: vec![1, 2, 3],
+            inits: inits.clone(),
+            drops: drops.clone(),
+        });
+        composer.force_frame().unwrap();
+
+        composer.set_root(A {
+            items: vec![3, 4, 1],
+            inits: inits.clone(),
+            drops: drops.clone(),
+        });
+        composer.force_frame().unwrap();
+
+        assert_eq!(*inits.borrow(), [1, 2, 3, 4]);
+        assert_eq!(*drops.borrow(), [2]);
+        assert_eq!(
+            format!("{:?}", composer),
+            "Composer(A(FromIterKeyed(B, B, B)))"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "duplicate key")]
+    fn it_rejects_duplicate_keys() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A;
+
+        impl Compose for A {
+            fn compose(_cx: Scope<Self>) -> impl Compose {
+                compose::from_iter_keyed(vec![0, 0], |item| *item, |_| ())
+            }
+        }
+
+        let mut composer = Composer::new(A);
+        composer.try_compose().unwrap();
+    }
+
+    #[test]
+    fn it_composes_with_budget() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A;
+
+        impl Compose for A {
+            fn compose(_cx: Scope<Self>) -> impl Compose {
+                (B, B)
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B;
+
+        impl Compose for B {
+            fn compose(_cx: Scope<Self>) -> impl Compose {}
+        }
+
+        let mut composer = Composer::new(A);
+
+        // `A`, its tuple, and the first `B` are each composed on their own call.
+        for _ in 0..3 {
+            assert_eq!(
+                composer.compose_with_budget(Duration::ZERO).unwrap(),
+                ComposeBudget::Exhausted
+            );
+        }
+        assert_eq!(
+            composer.compose_with_budget(Duration::ZERO).unwrap(),
+            ComposeBudget::Complete
+        );
+    }
+
+    #[test]
+    fn it_keys_memo() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            key: u32,
+            x: Rc<Cell<i32>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                keyed_memo(
+                    cx.me().key,
+                    B {
+                        x: cx.me().x.clone(),
+                    },
+                )
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            x: Rc<Cell<i32>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                use_ref(&cx, || cx.me().x.set(cx.me().x.get() + 1));
+            }
+        }
+
+        let x = Rc::new(Cell::new(0));
+        let mut composer = Composer::new(A {
+            key: 0,
+            x: x.clone(),
+        });
+        composer.force_frame().unwrap();
+        assert_eq!(x.get(), 1);
+
+        composer.set_root(A {
+            key: 0,
+            x: x.clone(),
+        });
+        composer.force_frame().unwrap();
+        assert_eq!(x.get(), 1);
+
+        composer.set_root(A {
+            key: 1,
+            x: x.clone(),
+        });
+        composer.force_frame().unwrap();
+        assert_eq!(x.get(), 2);
+    }
+
+    #[test]
+    fn it_composes_error_boundary_fallback() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Failing;
+
+        impl Compose for Failing {
+            fn compose(_cx: Scope<Self>) -> impl Compose {
+                let _: i32 = "".parse().map_err(Error::new)?;
+
+                Ok(())
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Fallback {
+            message: String,
+            messages: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl Compose for Fallback {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                cx.me().messages.borrow_mut().push(cx.me().message.clone());
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct App {
+            messages: Rc<RefCell<Vec<String>>>,
+        }
+
+        impl Compose for App {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                error_boundary(
+                    move |error| Fallback {
+                        message: error.to_string(),
+                        messages: cx.me().messages.clone(),
+                    },
+                    Failing,
+                )
+            }
+        }
+
+        let messages = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(App {
+            messages: messages.clone(),
+        });
+        composer.force_frame().unwrap();
+        composer.force_frame().unwrap();
+
+        assert_eq!(
+            *messages.borrow(),
+            ["cannot parse integer from empty string"]
+        );
+    }
+
+    #[test]
+    fn it_recovers_from_errors() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            is_failing: Rc<Cell<bool>>,
+            counts: Rc<RefCell<Vec<&'static str>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                cx.me().counts.borrow_mut().push("A");
+
+                B {
+                    is_failing: cx.me().is_failing.clone(),
+                    counts: cx.me().counts.clone(),
+                }
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            is_failing: Rc<Cell<bool>>,
+            counts: Rc<RefCell<Vec<&'static str>>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                cx.me().counts.borrow_mut().push("B");
+
+                let retry = use_mut(&cx, || ());
+                if cx.me().is_failing.take() {
+                    SignalMut::set(retry, ());
+
+                    let _: i32 = "".parse().map_err(Error::new)?;
+                }
+
+                Ok(C {
+                    counts: cx.me().counts.clone(),
+                })
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct C {
+            counts: Rc<RefCell<Vec<&'static str>>>,
+        }
+
+        impl Compose for C {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                cx.me().counts.borrow_mut().push("C");
+            }
+        }
+
+        let counts = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            is_failing: Rc::new(Cell::new(true)),
+            counts: counts.clone(),
+        });
+
+        assert!(matches!(
+            composer.try_compose(),
+            Err(TryComposeError::Error(_))
+        ));
+        assert_eq!(*counts.borrow(), ["A", "B"]);
+
+        // Apply the queued retry, then re-compose only the failed composable.
+        assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
+        assert_eq!(composer.try_compose(), Ok(()));
+        assert_eq!(*counts.borrow(), ["A", "B", "B", "C"]);
+    }
+
+    #[test]
+    fn it_batches_updates() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            values: Rc<RefCell<Vec<(i32, usize)>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let x = use_mut(&cx, || 0);
+
+                if *x == 0 {
+                    crate::batch(|| {
+                        SignalMut::set(x, 1);
+                        SignalMut::update(x, |x| *x += 1);
+                    });
+                }
+
+                cx.me()
+                    .values
+                    .borrow_mut()
+                    .push((*x, Runtime::current().update_queue.len()));
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            values: values.clone(),
+        });
+        composer.force_frame().unwrap();
+        composer.force_frame().unwrap();
+
+        assert_eq!(*values.borrow(), [(0, 1), (2, 0)]);
+    }
+
+    #[test]
+    fn it_flushes_batches_on_panic() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            values: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let x = use_mut(&cx, || 0);
+
+                if *x == 0 {
+                    // `resume_unwind` doesn't call the panic hook, so nothing is printed.
+                    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                        crate::batch(|| {
+                            SignalMut::set(x, 1);
+                            std::panic::resume_unwind(Box::new(()));
+                        })
+                    }));
+                    assert!(result.is_err());
+
+                    // This update must not be queued to the unwound batch.
+                    SignalMut::update(x, |x| *x += 1);
+                }
+
+                cx.me().values.borrow_mut().push(*x);
+            }
+        }
+
+        let values = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            values: values.clone(),
+        });
+        composer.force_frame().unwrap();
+        composer.force_frame().unwrap();
+
+        assert_eq!(*values.borrow(), [0, 2]);
+    }
+
+    #[test]
+    fn it_spawns_scope_tasks() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            count: Rc<Cell<i32>>,
+            scope_task_counts: Rc<RefCell<Vec<usize>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 2 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                let count = cx.me().count.clone();
+                cx.spawn(async move { count.set(count.get() + 1) });
+
+                cx.me()
+                    .scope_task_counts
+                    .borrow_mut()
+                    .push(cx.tasks.borrow().len());
+            }
+        }
+
+        let count = Rc::new(Cell::new(0));
+        let scope_task_counts = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            count: count.clone(),
+            scope_task_counts: scope_task_counts.clone(),
+        });
+        for _ in 0..3 {
+            composer.try_compose().unwrap();
+        }
+
+        assert_eq!(count.get(), 3);
+
+        // Completed tasks are removed from the runtime and the scope.
+        assert!(composer.rt.tasks.borrow().is_empty());
+        assert_eq!(*scope_task_counts.borrow(), [1, 1, 1]);
+    }
+
+    #[test]
+    fn it_spawns_blocking_tasks() {
+        let executor_cx = crate::executor::ExecutorContext::default();
+        let task = executor_cx.spawn_blocking(|| 1 + 1);
+
+        assert_eq!(futures::executor::block_on(task), 2);
+    }
+
+    #[test]
+    fn it_shares_runtimes() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            x: Rc<Cell<i32>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let is_updated = use_mut(&cx, || false);
+                if !*is_updated {
+                    SignalMut::set(is_updated, true);
+                }
+
+                cx.me().x.set(cx.me().x.get() + 1);
+            }
+        }
+
+        let x = Rc::new(Cell::new(0));
+        let y = Rc::new(Cell::new(0));
+        let mut a = Composer::new(A { x: x.clone() });
+        let mut b = Composer::new_with_runtime(A { x: y.clone() }, &a);
+
+        a.try_compose().unwrap();
+        b.try_compose().unwrap();
+
+        // Updates from both composers are applied by either.
+        for _ in 0..3 {
+            let _ = a.try_compose();
+        }
+        assert_eq!((x.get(), y.get()), (2, 2));
+
+        drop(a);
+        assert_eq!(format!("{:?}", b), "Composer(A)");
+    }
+
+    #[test]
+    fn it_cancels_composition() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A;
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                cx.spawn(futures::future::pending());
+            }
+        }
+
+        let mut composer = Composer::new(A);
+        composer.force_frame().unwrap();
+        assert_eq!(composer.rt.tasks.borrow().len(), 1);
+
+        composer.handle().cancel();
+
+        let waker = futures::task::noop_waker();
+        let mut cx = Context::from_waker(&waker);
+        assert!(matches!(
+            composer.poll_compose(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+        assert!(composer.rt.tasks.borrow().is_empty());
+    }
+
+    #[test]
+    fn it_composes_history() {
+        enum Action {
+            Push(i32),
+            Undo,
+            Redo,
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Editor {
+            actions: Rc<RefCell<Vec<Action>>>,
+            values: Rc<RefCell<Vec<(i32, bool, bool)>>>,
+        }
+
+        impl Compose for Editor {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                // Each action updates the history, which re-composes this editor to run the next action.
+                let history = use_history_with_capacity(&cx, 2, || 0);
+
+                cx.me().values.borrow_mut().push((
+                    *history,
+                    UseHistory::can_undo(history),
+                    UseHistory::can_redo(history),
+                ));
+
+                match cx.me().actions.borrow_mut().pop() {
+                    Some(Action::Push(value)) => UseHistory::push(history, value),
+                    Some(Action::Undo) => UseHistory::undo(history),
+                    Some(Action::Redo) => UseHistory::redo(history),
+                    None => {}
+                }
+            }
+        }
+
+        let actions = vec![
+            Action::Undo,
+            Action::Undo,
+            Action::Redo,
+            Action::Undo,
+            Action::Undo,
+            Action::Push(3),
+            Action::Push(2),
+            Action::Push(1),
+        ];
+        let actions = Rc::new(RefCell::new(actions));
+        let values = Rc::new(RefCell::new(Vec::new()));
+
+        let mut composer = Composer::new(Editor {
+            actions,
+            values: values.clone(),
+        });
+        for _ in 0..8 {
+            composer.try_compose().unwrap();
+        }
+
+        // The last undo has nothing to undo, so it doesn't trigger an update.
+        assert_eq!(composer.force_frame().unwrap(), 0);
+
+        // The oldest value is discarded once the history holds more than 2 undo states.
+        assert_eq!(
+            *values.borrow(),
+            [
+                (0, false, false),
+                (1, true, false),
+                (2, true, false),
+                (3, true, false),
+                (2, true, true),
+                (1, false, true),
+                (2, true, true),
+                (1, false, true),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_uses_stable_ids() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Child {
+            ids: Rc<RefCell<Vec<Id>>>,
+        }
+
+        impl Compose for Child {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                // Update this child once after it's first composed.
+                let (is_updated, set_is_updated) = use_state(&cx, || false);
+                if !is_updated {
+                    set_is_updated.set(true);
+                }
+
+                let ids = [use_id(&cx), use_id(&cx)];
+                cx.me().ids.borrow_mut().extend(ids);
+            }
+        }
+
+        let ids = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new((Child { ids: ids.clone() }, Child { ids: ids.clone() }));
+
+        composer.try_compose().unwrap();
+        let first = ids.take();
+        assert_eq!(first.len(), 4);
+        for (idx, id) in first.iter().enumerate() {
+            assert!(!first[idx + 1..].contains(id));
+        }
+
+        composer.try_compose().unwrap();
+        assert_eq!(ids.take(), first);
+    }
+
+    #[test]
+    fn it_sends_events_to_listeners() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Listener {
+            events: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for Listener {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let events = cx.me().events.clone();
+                use_listener(&cx, move |event: &i32| events.borrow_mut().push(*event)).unwrap();
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct Sender;
+
+        impl Compose for Sender {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let emitter = use_emitter::<i32>(&cx).unwrap();
+                use_ref(&cx, || emitter.emit(1));
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct App {
+            events: Rc<RefCell<Vec<i32>>>,
+        }
+
+        impl Compose for App {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                use_event_bus::<i32>(&cx);
+
+                (
+                    Listener {
+                        events: cx.me().events.clone(),
+                    },
+                    Sender,
+                )
+            }
+        }
+
+        let events = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(App {
+            events: events.clone(),
+        });
+        composer.force_frame().unwrap();
+        composer.force_frame().unwrap();
+
+        assert_eq!(*events.borrow(), [1]);
+    }
+
+    #[test]
+    fn it_composes_memo() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            x: Rc<RefCell<i32>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                *cx.me().x.borrow_mut() += 1;
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            x: Rc<RefCell<i32>>,
+        }
 
         impl Compose for A {
             fn compose(cx: Scope<Self>) -> impl Compose {
@@ -679,4 +2468,185 @@ mod tests {
         assert_eq!(composer.try_compose(), Err(TryComposeError::Pending));
         assert_eq!(*x.borrow(), 1);
     }
+
+    #[test]
+    fn it_composes_memo_always_if() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            x: Rc<RefCell<i32>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                *cx.me().x.borrow_mut() += 1;
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            x: Rc<RefCell<i32>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 2 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                let x = cx.me().x.clone();
+                memo_always_if(move || *step == 1, B { x })
+            }
+        }
+
+        let x = Rc::new(RefCell::new(0));
+        let mut composer = Composer::new(A { x: x.clone() });
+
+        composer.try_compose().unwrap();
+        assert_eq!(*x.borrow(), 1);
+
+        composer.try_compose().unwrap();
+        assert_eq!(*x.borrow(), 2);
+
+        composer.try_compose().unwrap();
+        assert_eq!(*x.borrow(), 2);
+    }
+
+    #[test]
+    fn it_shows_content() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            mounts: Rc<Cell<i32>>,
+            drops: Rc<Cell<i32>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                use_ref(&cx, || cx.me().mounts.set(cx.me().mounts.get() + 1));
+
+                let drops = cx.me().drops.clone();
+                use_drop(&cx, move || drops.set(drops.get() + 1));
+            }
+        }
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            mounts: Rc<Cell<i32>>,
+            drops: Rc<Cell<i32>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let step = use_mut(&cx, || 0);
+                if *step < 2 {
+                    SignalMut::update(step, |x| *x += 1);
+                }
+
+                show(
+                    *step != 1,
+                    B {
+                        mounts: cx.me().mounts.clone(),
+                        drops: cx.me().drops.clone(),
+                    },
+                )
+            }
+        }
+
+        let mounts = Rc::new(Cell::new(0));
+        let drops = Rc::new(Cell::new(0));
+        let mut composer = Composer::new(A {
+            mounts: mounts.clone(),
+            drops: drops.clone(),
+        });
+
+        composer.try_compose().unwrap();
+        assert_eq!((mounts.get(), drops.get()), (1, 0));
+
+        composer.try_compose().unwrap();
+        assert_eq!((mounts.get(), drops.get()), (1, 1));
+
+        composer.try_compose().unwrap();
+        assert_eq!((mounts.get(), drops.get()), (2, 1));
+    }
+
+    #[test]
+    fn it_uses_async() {
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct A {
+            states: Rc<RefCell<Vec<AsyncState<i32, ()>>>>,
+        }
+
+        impl Compose for A {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let state = use_async(&cx, (), || async { Ok(1) });
+                cx.me().states.borrow_mut().push(*state);
+            }
+        }
+
+        let states = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(A {
+            states: states.clone(),
+        });
+        for _ in 0..3 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(
+            *states.borrow(),
+            [AsyncState::Loading, AsyncState::Ready(1)]
+        );
+
+        #[derive(Data)]
+        #[actuate(path = "crate")]
+        struct B {
+            dependency: Rc<Cell<i32>>,
+            states: Rc<RefCell<Vec<AsyncState<i32, ()>>>>,
+        }
+
+        impl Compose for B {
+            fn compose(cx: Scope<Self>) -> impl Compose {
+                let dependency = cx.me().dependency.get();
+
+                // Only the first future completes.
+                let state = use_async(&cx, dependency, move || async move {
+                    if dependency == 0 {
+                        Ok(dependency)
+                    } else {
+                        std::future::pending().await
+                    }
+                });
+                cx.me().states.borrow_mut().push(*state);
+            }
+        }
+
+        let dependency = Rc::new(Cell::new(0));
+        let states = Rc::new(RefCell::new(Vec::new()));
+        let mut composer = Composer::new(B {
+            dependency: dependency.clone(),
+            states: states.clone(),
+        });
+        composer.next().unwrap().unwrap();
+
+        // Complete the first future, then change the dependency before its update is applied.
+        let rt = Runtime::current();
+        rt.batch(|| {
+            assert!(composer.next().is_none());
+
+            dependency.set(1);
+            rt.queue(rt.root);
+            composer.next().unwrap().unwrap();
+        });
+
+        for _ in 0..3 {
+            let _ = composer.try_compose();
+        }
+
+        assert_eq!(states.borrow().last(), Some(&AsyncState::Loading));
+        assert!(!states.borrow().contains(&AsyncState::Ready(0)));
+    }
 }
@@ -0,0 +1,304 @@
+use bevy_ecs::prelude::*;
+
+#[cfg(feature = "ui")]
+use crate::{compose::AnyCompose, composer::Runtime};
+
+#[cfg(feature = "ui")]
+use std::borrow::Cow;
+
+#[cfg(feature = "ui")]
+use bevy_color::{Alpha, Color};
+
+#[cfg(feature = "ui")]
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+
+#[cfg(feature = "ui")]
+use bevy_text::TextFont;
+
+#[cfg(feature = "ui")]
+use bevy_ui::{prelude::*, widget::Text, ComputedNode};
+
+/// Diagnostics overlay showing how many times each spawned entity's composable has recomposed.
+///
+/// When enabled, every entity spawned with [`spawn`](super::spawn) is outlined with a heat-map color
+/// (green for few recomposes, red for many) and labeled with its recompose count.
+/// This is useful for spotting composables that re-render more than expected.
+///
+/// Any `Outline` an entity had before it was outlined is restored when this overlay is disabled.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::RecomposeOverlay, prelude::*};
+/// use bevy::prelude::*;
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, ActuatePlugin))
+///     .insert_resource(RecomposeOverlay { is_enabled: true });
+/// ```
+#[derive(Clone, Copy, Debug, Default, Resource)]
+pub struct RecomposeOverlay {
+    /// Enable or disable this overlay.
+    pub is_enabled: bool,
+}
+
+/// Number of times the composable that spawned this entity has been composed.
+///
+/// This component is only updated while the [`RecomposeOverlay`] is enabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Component)]
+pub struct RecomposeCount(pub u64);
+
+/// Label entity of an outlined [`RecomposeCount`].
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct RecomposeLabel {
+    label: Entity,
+
+    /// Outline of the entity before it was outlined by the overlay, restored when the overlay is disabled.
+    outline: Option<Outline>,
+}
+
+/// Debug overlay showing the layout of every UI node.
+///
+/// When enabled, every UI node is outlined with its computed rectangle
+/// and labeled with its size in logical pixels.
+/// Entities spawned with [`spawn`](super::spawn) while this overlay is enabled are also labeled
+/// with the name of the composable that spawned them.
+/// This is useful for finding out why a node is laid out with an unexpected size.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::LayoutOverlay, prelude::*};
+/// use bevy::prelude::*;
+///
+/// fn toggle_overlay(keys: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<LayoutOverlay>) {
+///     if keys.just_pressed(KeyCode::F1) {
+///         overlay.is_enabled = !overlay.is_enabled;
+///     }
+/// }
+///
+/// App::new()
+///     .add_plugins((DefaultPlugins, ActuatePlugin))
+///     .init_resource::<LayoutOverlay>()
+///     .add_systems(Update, toggle_overlay);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Resource)]
+pub struct LayoutOverlay {
+    /// Enable or disable this overlay.
+    pub is_enabled: bool,
+}
+
+/// Name of the composable that spawned this entity.
+///
+/// This component is only inserted while the [`LayoutOverlay`] is enabled.
+#[cfg(feature = "ui")]
+#[derive(Clone, Debug, Component)]
+pub(crate) struct ComposableName(pub(crate) Cow<'static, str>);
+
+/// Label entity of a node outlined by the [`LayoutOverlay`].
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct LayoutLabel(Entity);
+
+/// Marker for the label entities of the [`LayoutOverlay`], which aren't outlined themselves.
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct LayoutLabelText;
+
+/// Returns `true` if the [`RecomposeOverlay`] is enabled in the `world`.
+pub(crate) fn is_overlay_enabled(world: &World) -> bool {
+    world
+        .get_resource::<RecomposeOverlay>()
+        .is_some_and(|overlay| overlay.is_enabled)
+}
+
+/// Returns `true` if the [`LayoutOverlay`] is enabled in the `world`.
+#[cfg(feature = "ui")]
+pub(crate) fn is_layout_overlay_enabled(world: &World) -> bool {
+    world
+        .get_resource::<LayoutOverlay>()
+        .is_some_and(|overlay| overlay.is_enabled)
+}
+
+/// Get the name of the closest named composable above the [`Spawn`](super::Spawn) composable currently composing.
+#[cfg(feature = "ui")]
+pub(crate) fn composable_name(rt: &Runtime) -> Option<Cow<'static, str>> {
+    let nodes = rt.nodes.borrow();
+    let mut parent = nodes.get(rt.current_key.get())?.parent;
+
+    while let Some(key) = parent {
+        let node = nodes.get(key)?;
+
+        // Skip the spawn composables of parent entities.
+        if let Some(name) = node
+            .compose
+            .try_borrow()
+            .ok()
+            .and_then(|compose| compose.name())
+        {
+            if name != "Spawn" {
+                return Some(name);
+            }
+        }
+
+        parent = node.parent;
+    }
+
+    None
+}
+
+#[cfg(feature = "ui")]
+fn heat_color(count: u64) -> Color {
+    // Scale logarithmically so 1 recompose is green and 1000+ recomposes are red.
+    let t = ((count.max(1) as f32).ln() / 1000f32.ln()).clamp(0., 1.);
+    Color::hsl(120. * (1. - t), 1., 0.5)
+}
+
+#[cfg(feature = "ui")]
+type RecomposeQueryData<'a> = (
+    Entity,
+    Ref<'a, RecomposeCount>,
+    Option<&'a RecomposeLabel>,
+    Option<&'a Outline>,
+);
+
+/// Draw or clear the [`RecomposeOverlay`].
+#[cfg(feature = "ui")]
+pub(crate) fn update_recompose_overlay(
+    mut commands: Commands,
+    overlay: Option<Res<RecomposeOverlay>>,
+    count_query: Query<RecomposeQueryData>,
+    mut label_query: Query<(&mut Text, &mut BackgroundColor)>,
+) {
+    let is_enabled = overlay.is_some_and(|overlay| overlay.is_enabled);
+
+    for (entity, count, label, outline) in &count_query {
+        if !is_enabled {
+            if let Some(label) = label {
+                commands.entity(label.label).despawn_recursive();
+
+                // Restore the outline this entity had before it was outlined by the overlay.
+                match label.outline {
+                    Some(outline) => commands.entity(entity).insert(outline),
+                    None => commands.entity(entity).remove::<Outline>(),
+                };
+            }
+
+            commands
+                .entity(entity)
+                .remove::<(RecomposeCount, RecomposeLabel)>();
+            continue;
+        }
+
+        if !count.is_changed() {
+            continue;
+        }
+
+        let color = heat_color(count.0);
+        commands
+            .entity(entity)
+            .insert(Outline::new(Val::Px(1.), Val::ZERO, color));
+
+        if let Some(label) = label {
+            if let Ok((mut text, mut background_color)) = label_query.get_mut(label.label) {
+                text.0 = count.0.to_string();
+                background_color.0 = color.with_alpha(0.5);
+            }
+        } else {
+            let label = commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        top: Val::ZERO,
+                        left: Val::ZERO,
+                        ..Default::default()
+                    },
+                    Text::new(count.0.to_string()),
+                    BackgroundColor(color.with_alpha(0.5)),
+                    GlobalZIndex(i32::MAX),
+                ))
+                .id();
+
+            commands
+                .entity(entity)
+                .add_child(label)
+                .insert(RecomposeLabel {
+                    label,
+                    outline: outline.copied(),
+                });
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+type LayoutQueryData<'a> = (
+    Entity,
+    Ref<'a, ComputedNode>,
+    Option<Ref<'a, ComposableName>>,
+    Option<&'a LayoutLabel>,
+);
+
+/// Draw or clear the [`LayoutOverlay`].
+#[cfg(feature = "ui")]
+pub(crate) fn update_layout_overlay(
+    mut commands: Commands,
+    overlay: Option<Res<LayoutOverlay>>,
+    node_query: Query<LayoutQueryData, Without<LayoutLabelText>>,
+    mut label_query: Query<&mut Text, With<LayoutLabelText>>,
+) {
+    let is_enabled = overlay.is_some_and(|overlay| overlay.is_enabled);
+
+    for (entity, computed_node, name, label) in &node_query {
+        if !is_enabled {
+            if let Some(label) = label {
+                commands.entity(label.0).despawn_recursive();
+                commands
+                    .entity(entity)
+                    .remove::<(ComposableName, LayoutLabel, Outline)>();
+            }
+            continue;
+        }
+
+        let is_name_changed = name.as_ref().is_some_and(|name| name.is_changed());
+        if label.is_some() && !computed_node.is_changed() && !is_name_changed {
+            continue;
+        }
+
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let content = match &name {
+            Some(name) => format!("{} {}x{}", name.0, size.x.round(), size.y.round()),
+            None => format!("{}x{}", size.x.round(), size.y.round()),
+        };
+
+        if let Some(label) = label {
+            if let Ok(mut text) = label_query.get_mut(label.0) {
+                text.0 = content;
+            }
+        } else {
+            // Give each node its own color, so nested outlines can be told apart.
+            let color = Color::hsl((entity.index() as f32 * 47.) % 360., 1., 0.5);
+
+            let label = commands
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        bottom: Val::ZERO,
+                        right: Val::ZERO,
+                        ..Default::default()
+                    },
+                    Text::new(content),
+                    TextFont::from_font_size(10.),
+                    BackgroundColor(color.with_alpha(0.5)),
+                    GlobalZIndex(i32::MAX),
+                    LayoutLabelText,
+                ))
+                .id();
+
+            commands.entity(entity).add_child(label).insert((
+                Outline::new(Val::Px(1.), Val::ZERO, color),
+                LayoutLabel(label),
+            ));
+        }
+    }
+}
@@ -0,0 +1,278 @@
+use super::{use_world, RuntimeContext};
+use crate::{composer::Runtime, data::Data, use_drop, use_mut, use_ref, ScopeState, SignalMut};
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+
+/// Keyboard focus of composables.
+///
+/// Each [`use_focus`] hook registers a focusable entity in this resource,
+/// ordered by its position in the composition.
+/// Pressing Tab moves focus to the next focusable, and Shift + Tab moves it to the previous one.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::InputFocus, prelude::*};
+/// use bevy::prelude::*;
+///
+/// fn clear_focus(keys: Res<ButtonInput<KeyCode>>, mut focus: ResMut<InputFocus>) {
+///     if keys.just_pressed(KeyCode::Escape) {
+///         focus.set(None);
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Resource)]
+pub struct InputFocus {
+    focused: Option<Entity>,
+
+    /// Focusable entities, sorted by their position in the composition.
+    focusables: Vec<(Vec<usize>, Entity)>,
+}
+
+impl InputFocus {
+    /// Get the currently focused entity.
+    pub fn get(&self) -> Option<Entity> {
+        self.focused
+    }
+
+    /// Set the currently focused entity, or `None` to clear focus.
+    pub fn set(&mut self, entity: Option<Entity>) {
+        self.focused = entity;
+    }
+
+    /// Move focus to the next focusable entity, wrapping around to the first.
+    pub fn focus_next(&mut self) {
+        let idx = self
+            .focused_idx()
+            .map(|idx| (idx + 1) % self.focusables.len())
+            .unwrap_or(0);
+        self.focused = self.focusables.get(idx).map(|(_, entity)| *entity);
+    }
+
+    /// Move focus to the previous focusable entity, wrapping around to the last.
+    pub fn focus_previous(&mut self) {
+        let idx = self
+            .focused_idx()
+            .map(|idx| idx.checked_sub(1).unwrap_or(self.focusables.len() - 1))
+            .unwrap_or(self.focusables.len().saturating_sub(1));
+        self.focused = self.focusables.get(idx).map(|(_, entity)| *entity);
+    }
+
+    fn focused_idx(&self) -> Option<usize> {
+        let focused = self.focused?;
+        self.focusables
+            .iter()
+            .position(|(_, entity)| *entity == focused)
+    }
+
+    fn insert(&mut self, indices: Vec<usize>, entity: Entity) {
+        let idx = self
+            .focusables
+            .partition_point(|(other, _)| *other <= indices);
+        self.focusables.insert(idx, (indices, entity));
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        self.focusables.retain(|(_, other)| *other != entity);
+
+        if self.focused == Some(entity) {
+            self.focused = None;
+        }
+    }
+}
+
+/// Hook for [`use_focus`].
+pub struct UseFocus<'a> {
+    entity: Entity,
+    is_focused: SignalMut<'a, bool>,
+}
+
+impl UseFocus<'_> {
+    /// Get the entity used to identify this focusable in the [`InputFocus`] resource.
+    pub fn entity(me: Self) -> Entity {
+        me.entity
+    }
+
+    /// Returns `true` if this composable currently has focus.
+    pub fn is_focused(me: Self) -> bool {
+        *me.is_focused
+    }
+
+    /// Queue a request to focus this composable.
+    pub fn focus(me: Self) {
+        let entity = me.entity;
+        queue_focus_update(move |focus| focus.set(Some(entity)));
+    }
+
+    /// Queue a request to remove focus from this composable, if it has focus.
+    pub fn blur(me: Self) {
+        let entity = me.entity;
+        queue_focus_update(move |focus| {
+            if focus.get() == Some(entity) {
+                focus.set(None);
+            }
+        });
+    }
+}
+
+impl Clone for UseFocus<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for UseFocus<'_> {}
+
+unsafe impl Send for UseFocus<'_> {}
+
+unsafe impl Sync for UseFocus<'_> {}
+
+unsafe impl Data for UseFocus<'_> {}
+
+fn queue_focus_update(f: impl FnOnce(&mut InputFocus) + Send + 'static) {
+    let commands = RuntimeContext::current().inner.borrow().commands.clone();
+    commands.borrow_mut().push(move |world: &mut World| {
+        if let Some(mut focus) = world.get_resource_mut::<InputFocus>() {
+            f(&mut focus);
+        }
+    });
+}
+
+/// Use keyboard focus for the current composable.
+///
+/// This will re-compose the current composable when it gains or loses focus.
+/// Focus can be requested with [`UseFocus::focus`], or moved between composables with Tab and Shift + Tab.
+///
+/// To receive keyboard input while focused, see [`use_keyboard_input`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Field;
+///
+/// impl Compose for Field {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let focus = use_focus(&cx);
+///
+///         let color = if UseFocus::is_focused(focus) {
+///             Color::WHITE
+///         } else {
+///             Color::srgb(0.5, 0.5, 0.5)
+///         };
+///
+///         spawn((Text::new("Field"), TextColor(color)))
+///             .observe(move |_: Trigger<Pointer<Click>>| UseFocus::focus(focus))
+///     }
+/// }
+/// ```
+pub fn use_focus(cx: ScopeState<'_>) -> UseFocus<'_> {
+    let entity = *use_ref(cx, || {
+        let rt = Runtime::current();
+        let indices = rt.pending(rt.current_key.get()).indices;
+
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        let entity = world.spawn_empty().id();
+        world.resource_mut::<InputFocus>().insert(indices, entity);
+        entity
+    });
+
+    use_drop(cx, move || {
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        if let Some(mut focus) = world.get_resource_mut::<InputFocus>() {
+            focus.remove(entity);
+        }
+        world.try_despawn(entity);
+    });
+
+    let is_focused = use_mut(cx, || false);
+    use_world(cx, move |focus: Res<InputFocus>| {
+        SignalMut::set_if_neq(is_focused, focus.get() == Some(entity));
+    });
+
+    UseFocus { entity, is_focused }
+}
+
+/// Use keyboard input for the current composable, calling `f` for every keyboard event while `focus` is focused.
+///
+/// Tab key events used to move focus are not passed to `f`.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::{
+///     input::{
+///         keyboard::{Key, KeyboardInput},
+///         ButtonState,
+///     },
+///     prelude::*,
+/// };
+///
+/// #[derive(Data)]
+/// struct TextField;
+///
+/// impl Compose for TextField {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let text = use_mut(&cx, String::new);
+///
+///         let focus = use_focus(&cx);
+///         use_keyboard_input(&cx, focus, move |event: &KeyboardInput| {
+///             if let (ButtonState::Pressed, Key::Character(c)) = (event.state, &event.logical_key) {
+///                 let c = c.clone();
+///                 SignalMut::update(text, move |text| text.push_str(&c));
+///             }
+///         });
+///
+///         spawn(Text::new((*text).clone()))
+///             .observe(move |_: Trigger<Pointer<Click>>| UseFocus::focus(focus))
+///     }
+/// }
+/// ```
+pub fn use_keyboard_input<'a>(
+    cx: ScopeState<'a>,
+    focus: UseFocus<'a>,
+    mut f: impl FnMut(&KeyboardInput) + 'a,
+) {
+    use_world(
+        cx,
+        move |mut keyboard_input_events: EventReader<KeyboardInput>,
+              input_focus: Res<InputFocus>| {
+            if input_focus.get() != Some(focus.entity) {
+                keyboard_input_events.clear();
+                return;
+            }
+
+            for event in keyboard_input_events.read() {
+                if event.key_code != KeyCode::Tab {
+                    f(event);
+                }
+            }
+        },
+    );
+}
+
+/// Move the [`InputFocus`] on Tab and Shift + Tab.
+pub(crate) fn cycle_focus(
+    mut keyboard_input_events: EventReader<KeyboardInput>,
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut focus: ResMut<InputFocus>,
+) {
+    for event in keyboard_input_events.read() {
+        if event.key_code != KeyCode::Tab || event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        if keyboard_input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+            focus.focus_previous();
+        } else {
+            focus.focus_next();
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use bevy_ecs::prelude::*;
+use std::{
+    thread,
+    time::{Duration, Instant},
+};
+
+/// Frame rate limiter for apps that render without vsync.
+///
+/// With a present mode like [`PresentMode::AutoNoVsync`](bevy_window::PresentMode::AutoNoVsync),
+/// the app renders as fast as possible.
+/// Inserting this resource with a target frame rate sleeps at the end of each frame until the next frame is due,
+/// so frames are spaced evenly at the target interval (including the time spent updating and rendering).
+///
+/// Remove this resource (or set `target_fps` to `None`) to render uncapped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::FrameLimiter, prelude::*};
+/// use bevy::{prelude::*, window::PresentMode};
+///
+/// App::new()
+///     .add_plugins((
+///         DefaultPlugins.set(WindowPlugin {
+///             primary_window: Some(Window {
+///                 present_mode: PresentMode::AutoNoVsync,
+///                 ..default()
+///             }),
+///             ..default()
+///         }),
+///         ActuatePlugin,
+///     ))
+///     .insert_resource(FrameLimiter::new(120.));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Resource)]
+pub struct FrameLimiter {
+    /// Target frames per second, or `None` to render uncapped.
+    pub target_fps: Option<f64>,
+}
+
+impl FrameLimiter {
+    /// Create a new frame limiter with a target frame rate in frames per second.
+    pub fn new(target_fps: f64) -> Self {
+        Self {
+            target_fps: Some(target_fps),
+        }
+    }
+
+    /// Get the target interval between frames, if a valid target frame rate is set.
+    pub fn interval(&self) -> Option<Duration> {
+        self.target_fps
+            .filter(|fps| fps.is_finite() && *fps > 0.)
+            .map(|fps| Duration::from_secs_f64(1. / fps))
+    }
+}
+
+/// Sleep until the next frame is due for the [`FrameLimiter`].
+pub(crate) fn limit_frame_rate(limiter: Res<FrameLimiter>, mut next_frame: Local<Option<Instant>>) {
+    let Some(interval) = limiter.interval() else {
+        *next_frame = None;
+        return;
+    };
+
+    let now = Instant::now();
+    let deadline = next_frame.unwrap_or(now);
+    if deadline > now {
+        thread::sleep(deadline - now);
+    }
+
+    // Schedule the next frame from this frame's deadline to keep an even pace,
+    // unless this frame ran late (then start pacing again from now).
+    let frame_start = deadline.max(now);
+    *next_frame = Some(frame_start + interval);
+}
@@ -0,0 +1,167 @@
+use super::InputEvent;
+use bevy_ecs::{
+    event::{EventCursor, Events},
+    prelude::*,
+};
+use bevy_input::{
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseWheel},
+};
+use bevy_window::{CursorMoved, WindowResized};
+
+/// Flow of an input event through an [`EventInterceptor`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum EventFlow {
+    /// Continue routing this event.
+    #[default]
+    Continue,
+
+    /// Consume this event, removing it before it reaches any other system.
+    Consume,
+}
+
+type InterceptorFn = Box<dyn Fn(&InputEvent) -> EventFlow + Send + Sync>;
+
+/// Interceptor for input events.
+///
+/// Interceptors run at the top of the event pipeline, before input is processed or routed to any entity
+/// (including replayed events from an [`EventReplay`](super::EventReplay)).
+/// Each interceptor can observe every pointer, keyboard, and resize event, and optionally consume it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{
+///     ecs::{EventFlow, EventInterceptor, InputEvent},
+///     prelude::*,
+/// };
+/// use bevy::prelude::*;
+///
+/// fn setup(mut interceptor: ResMut<EventInterceptor>) {
+///     interceptor.on_event(|event| {
+///         info!("{event:?}");
+///
+///         // Block the escape key globally.
+///         match event {
+///             InputEvent::Keyboard(keyboard) if keyboard.key_code == KeyCode::Escape => {
+///                 EventFlow::Consume
+///             }
+///             _ => EventFlow::Continue,
+///         }
+///     });
+/// }
+/// ```
+#[derive(Default, Resource)]
+pub struct EventInterceptor {
+    fns: Vec<InterceptorFn>,
+}
+
+impl EventInterceptor {
+    /// Add a function to be called for every input event.
+    ///
+    /// Interceptors run in the order they were added,
+    /// and an event is not passed to later interceptors once it is consumed.
+    pub fn on_event(
+        &mut self,
+        f: impl Fn(&InputEvent) -> EventFlow + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.fns.push(Box::new(f));
+        self
+    }
+
+    /// Returns `true` if this interceptor has no functions.
+    pub fn is_empty(&self) -> bool {
+        self.fns.is_empty()
+    }
+
+    fn intercept(&self, event: &InputEvent) -> EventFlow {
+        for f in &self.fns {
+            if f(event) == EventFlow::Consume {
+                return EventFlow::Consume;
+            }
+        }
+        EventFlow::Continue
+    }
+}
+
+#[derive(Default)]
+pub(crate) struct InterceptCursors {
+    cursor_moved: EventCursor<CursorMoved>,
+    mouse_button: EventCursor<MouseButtonInput>,
+    mouse_wheel: EventCursor<MouseWheel>,
+    keyboard: EventCursor<KeyboardInput>,
+    window_resized: EventCursor<WindowResized>,
+}
+
+/// Returns `true` if the [`EventInterceptor`] has any functions.
+pub(crate) fn has_interceptors(interceptor: Option<Res<EventInterceptor>>) -> bool {
+    interceptor.is_some_and(|interceptor| !interceptor.is_empty())
+}
+
+pub(crate) fn intercept_events(world: &mut World, mut cursors: Local<InterceptCursors>) {
+    world.resource_scope(|world, interceptor: Mut<EventInterceptor>| {
+        intercept(
+            world,
+            &interceptor,
+            &mut cursors.cursor_moved,
+            InputEvent::CursorMoved,
+        );
+        intercept(
+            world,
+            &interceptor,
+            &mut cursors.mouse_button,
+            InputEvent::MouseButton,
+        );
+        intercept(
+            world,
+            &interceptor,
+            &mut cursors.mouse_wheel,
+            InputEvent::MouseWheel,
+        );
+        intercept(
+            world,
+            &interceptor,
+            &mut cursors.keyboard,
+            InputEvent::Keyboard,
+        );
+        intercept(
+            world,
+            &interceptor,
+            &mut cursors.window_resized,
+            InputEvent::WindowResized,
+        );
+    });
+}
+
+/// Run the `interceptor` on every new event of type `E`, removing any consumed events.
+fn intercept<E: Event + Clone>(
+    world: &mut World,
+    interceptor: &EventInterceptor,
+    cursor: &mut EventCursor<E>,
+    make_input_event: fn(E) -> InputEvent,
+) {
+    let Some(mut events) = world.get_resource_mut::<Events<E>>() else {
+        return;
+    };
+
+    let new_events: Vec<E> = cursor.read(&events).cloned().collect();
+    let kept_events: Vec<E> = new_events
+        .iter()
+        .filter(|event| {
+            interceptor.intercept(&make_input_event((*event).clone())) == EventFlow::Continue
+        })
+        .cloned()
+        .collect();
+
+    if kept_events.len() == new_events.len() {
+        return;
+    }
+
+    // Events can't be removed individually, so clear them and re-send the events that were kept.
+    // Events from the previous frame have already been read, so they are not re-sent.
+    events.drain().for_each(drop);
+    events.send_batch(kept_events);
+
+    // Skip over the re-sent events.
+    cursor.read(&events).for_each(drop);
+}
@@ -25,15 +25,52 @@ use std::{
     task::{Context, Wake, Waker},
 };
 
+#[cfg(feature = "ui")]
+use bevy_color::Color;
 #[cfg(feature = "ui")]
 use bevy_ui::prelude::*;
 
 #[cfg(feature = "picking")]
 use bevy_picking::prelude::*;
 
+mod diagnostics;
+pub use self::diagnostics::{LayoutOverlay, RecomposeCount, RecomposeOverlay};
+
+mod focus;
+pub use self::focus::{use_focus, use_keyboard_input, InputFocus, UseFocus};
+
+mod frame_limit;
+pub use self::frame_limit::FrameLimiter;
+
+mod intercept;
+pub use self::intercept::{EventFlow, EventInterceptor};
+
+mod portal;
+pub use self::portal::{portal, Portal};
+
+mod record;
+pub use self::record::{EventRecorder, EventRecording, EventReplay, InputEvent, RecordedEvent};
+
+mod shortcut;
+pub use self::shortcut::{use_sequence, use_sequence_with_timeout, KeyCombo};
+
 mod spawn;
 pub use self::spawn::{spawn, Spawn};
 
+mod timer;
+pub use self::timer::use_interval;
+
+mod window;
+pub use self::window::{
+    use_cursor, use_dropped_files, use_monitors, use_on_close, use_pointer_position,
+    use_window_handle, use_window_size, use_window_size_with_debounce, use_window_state,
+    MonitorInfo, UseWindowState, WindowState,
+};
+
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub use self::window::{window, WindowRoot};
+
 macro_rules! impl_trait_for_tuples {
     ($t:tt) => {
         $t!();
@@ -58,7 +95,46 @@ impl Plugin for ActuatePlugin {
         };
 
         app.insert_non_send_resource(rt)
-            .add_systems(bevy_app::prelude::Update, compose);
+            .init_resource::<EventRecorder>()
+            .init_resource::<EventInterceptor>()
+            .init_resource::<InputFocus>()
+            .add_systems(
+                bevy_app::prelude::First,
+                (
+                    record::replay_events
+                        .run_if(resource_exists::<EventReplay>)
+                        .after(bevy_time::TimeSystem),
+                    intercept::intercept_events
+                        .run_if(intercept::has_interceptors)
+                        .after(bevy_ecs::event::EventUpdates),
+                )
+                    .chain(),
+            )
+            .add_systems(
+                bevy_app::prelude::Update,
+                (focus::cycle_focus, compose)
+                    .chain()
+                    .before(bevy_window::close_when_requested),
+            )
+            .add_systems(
+                bevy_app::prelude::Last,
+                (
+                    record::record_events.run_if(record::is_recording),
+                    frame_limit::limit_frame_rate.run_if(resource_exists::<FrameLimiter>),
+                ),
+            );
+
+        #[cfg(feature = "ui")]
+        app.add_systems(
+            bevy_app::prelude::PostUpdate,
+            (
+                window::size_to_content.after(bevy_ui::UiSystem::Layout),
+                crate::ui::trigger_resized.after(bevy_ui::UiSystem::Layout),
+                diagnostics::update_recompose_overlay.before(bevy_ui::UiSystem::Prepare),
+                diagnostics::update_layout_overlay.after(bevy_ui::UiSystem::Layout),
+                crate::ui::fade_subtrees.before(bevy_ui::UiSystem::Prepare),
+            ),
+        );
     }
 }
 
@@ -110,6 +186,8 @@ struct Runtime {
 pub struct Composition<C> {
     content: Option<C>,
     target: Option<Entity>,
+    #[cfg(feature = "ui")]
+    size_to_content: bool,
 }
 
 impl<C> Composition<C>
@@ -121,6 +199,8 @@ where
         Self {
             content: Some(content),
             target: None,
+            #[cfg(feature = "ui")]
+            size_to_content: false,
         }
     }
 
@@ -145,6 +225,37 @@ where
         self.target = Some(target);
         self
     }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Returns `true` if the primary window will be sized to this composition's content.
+    pub fn size_to_content(&self) -> bool {
+        self.size_to_content
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set whether the primary window should be sized to this composition's content.
+    ///
+    /// See [`Composition::with_size_to_content`] for more.
+    pub fn set_size_to_content(&mut self, size_to_content: bool) {
+        self.size_to_content = size_to_content;
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Size the primary window to this composition's content.
+    ///
+    /// After the first layout of the target node, the primary window is resized to fit it
+    /// (clamped to the window's resize constraints and the primary monitor) and then made visible.
+    /// To prevent the initial frame from flashing at the wrong size,
+    /// create the primary window with `visible: false`.
+    ///
+    /// The window is only resized once, runtime changes in content size are ignored.
+    pub fn with_size_to_content(mut self, size_to_content: bool) -> Self {
+        self.size_to_content = size_to_content;
+        self
+    }
 }
 
 impl<C> Component for Composition<C>
@@ -161,6 +272,11 @@ where
                 let content = composition.content.take().unwrap();
                 let target = composition.target.unwrap_or(entity);
 
+                #[cfg(feature = "ui")]
+                if composition.size_to_content {
+                    world.entity_mut(target).insert(window::SizeToContent);
+                }
+
                 let rt = world.non_send_resource_mut::<Runtime>();
 
                 rt.composers.borrow_mut().insert(
@@ -203,6 +319,9 @@ impl Wake for RuntimeWaker {
 }
 
 fn compose(world: &mut World) {
+    #[cfg(feature = "tracing")]
+    let _span = tracing::debug_span!("actuate_compose").entered();
+
     RUNTIME_CONTEXT.with(|runtime_cx| {
         let mut cell = runtime_cx.borrow_mut();
         let runtime_cx = cell.get_or_insert_with(|| RuntimeContext {
@@ -216,6 +335,9 @@ fn compose(world: &mut World) {
 
         runtime_cx.inner.borrow_mut().world_ptr = world as *mut World;
 
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("run_listeners").entered();
+
         for f in runtime_cx.inner.borrow().listeners.values() {
             f(world)
         }
@@ -492,8 +614,8 @@ pub fn use_commands(cx: ScopeState) -> &UseCommands {
     })
 }
 
-struct SpawnContext {
-    parent_entity: Entity,
+pub(crate) struct SpawnContext {
+    pub(crate) parent_entity: Entity,
     keys: RefCell<BTreeSet<Pending>>,
 }
 
@@ -638,6 +760,220 @@ pub trait Modify<'a> {
         })
     }
 
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Add a function to be called with the size of this composable's spawned [`Node`],
+    /// in logical pixels, whenever it changes after layout.
+    ///
+    /// This is also called with the initial size after the first layout pass.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Chart;
+    ///
+    /// impl Compose for Chart {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let size = use_mut(&cx, || (0., 0.));
+    ///
+    ///         // Only rebuild the bars when the chart is resized.
+    ///         let bar_count = use_memo(&cx, size.0 as u32, || (size.0 / 20.) as usize);
+    ///
+    ///         grid(compose::from_iter(vec![(); *bar_count], |_| text::label("|")))
+    ///             .columns(vec![RepeatedGridTrack::flex(*bar_count as u16, 1.)])
+    ///             .width(Val::Percent(100.))
+    ///             .on_resize(move |new_size| SignalMut::set_if_neq(size, new_size))
+    ///     }
+    /// }
+    /// ```
+    fn on_resize(self, f: impl Fn((f32, f32)) + Send + Sync + 'a) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(|mut entity| {
+            if !entity.contains::<crate::ui::LastSize>() {
+                entity.insert(crate::ui::LastSize::default());
+            }
+        })
+        .observe(move |trigger: Trigger<crate::ui::Resized>| f(trigger.size))
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set a function to measure the content size of this composable's spawned [`Node`] during layout.
+    ///
+    /// `f` is called with the known and available sizes of the node, in logical pixels,
+    /// and returns the size of its content.
+    /// This lets content whose size depends on the available space (like wrapped content)
+    /// report its intrinsic size to layout.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::{prelude::*, ui::measurement::AvailableSpace};
+    ///
+    /// #[derive(Data)]
+    /// struct Square;
+    ///
+    /// impl Compose for Square {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         // Fill the available width, and use the same height.
+    ///         rect(Color::BLACK).measure(|args| {
+    ///             let width = args.width.unwrap_or(match args.available_width {
+    ///                 AvailableSpace::Definite(width) => width,
+    ///                 _ => 100.,
+    ///             });
+    ///             Vec2::splat(width)
+    ///         })
+    ///     }
+    /// }
+    /// ```
+    fn measure(
+        self,
+        f: impl Fn(bevy_ui::measurement::MeasureArgs) -> bevy_math::Vec2 + Send + Sync + 'static,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        let f: crate::ui::MeasureFn = Arc::new(f);
+        self.on_insert(move |mut entity| {
+            let mut content_size = bevy_ui::ContentSize::default();
+            content_size.set(bevy_ui::NodeMeasure::Custom(Box::new(
+                crate::ui::FnMeasure(f.clone()),
+            )));
+            entity.insert(content_size);
+        })
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set the z-index of this composable's spawned [`Node`] relative to its siblings.
+    ///
+    /// Nodes with a higher z-index are drawn on top of nodes with a lower z-index,
+    /// and nodes with the same z-index are drawn in the order they're composed.
+    /// To draw above every other node (e.g. for tooltips or dropdowns), see [`Modify::global_z_index`].
+    fn z_index(self, z_index: i32) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(move |mut entity| {
+            entity.insert(ZIndex(z_index));
+        })
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set the global z-index of this composable's spawned [`Node`].
+    ///
+    /// Unlike [`Modify::z_index`], this is compared against every node in the UI (not only siblings),
+    /// so the node can be drawn above content outside of its parent.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Tooltip;
+    ///
+    /// impl Compose for Tooltip {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         container(text::label("Tooltip"))
+    ///             .position_type(PositionType::Absolute)
+    ///             .global_z_index(100)
+    ///     }
+    /// }
+    /// ```
+    fn global_z_index(self, z_index: i32) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(move |mut entity| {
+            entity.insert(GlobalZIndex(z_index));
+        })
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Set the border color of this composable's spawned [`Node`].
+    ///
+    /// The width of the border is set with [`Modify::border`].
+    /// For a [`RectView`](crate::ui::RectView), both can be set at once with [`RectView::stroke`](crate::ui::RectView::stroke).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Panel;
+    ///
+    /// impl Compose for Panel {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         container(text::label("Panel"))
+    ///             .border(UiRect::all(Val::Px(2.)))
+    ///             .border_color(Color::WHITE)
+    ///     }
+    /// }
+    /// ```
+    fn border_color(self, color: Color) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(move |mut entity| {
+            entity.insert(BorderColor(color));
+        })
+    }
+
+    #[cfg(feature = "ui")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+    /// Add a drop shadow behind this composable's spawned [`Node`].
+    ///
+    /// The shadow is offset by `x_offset` and `y_offset`, blurred by `blur_radius`,
+    /// and follows the node's [`BorderRadius`].
+    /// It's drawn behind the node (and its content), and isn't clipped by the node itself.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Card;
+    ///
+    /// impl Compose for Card {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         rect(Color::WHITE)
+    ///             .corner_radius(Val::Px(8.))
+    ///             .shadow(Val::Px(0.), Val::Px(4.), Val::Px(12.), Color::srgba(0., 0., 0., 0.3))
+    ///             .width(Val::Px(200.))
+    ///             .height(Val::Px(120.))
+    ///     }
+    /// }
+    /// ```
+    fn shadow(self, x_offset: Val, y_offset: Val, blur_radius: Val, color: Color) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_insert(move |mut entity| {
+            entity.insert(BoxShadow {
+                color,
+                x_offset,
+                y_offset,
+                spread_radius: Val::ZERO,
+                blur_radius,
+            });
+        })
+    }
+
     #[cfg(feature = "ui")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
     /// Set the flex gap of this composable's spawned [`Node`].
@@ -729,4 +1065,145 @@ pub trait Modify<'a> {
         on_drag_drop: DragDrop,
         on_drag_leave: DragLeave
     );
+
+    /// Add a function to be called when this composable is clicked with the secondary (usually right) mouse button.
+    ///
+    /// Like [`Modify::on_click`], this is called once the button is pressed and released on this composable.
+    /// To open a context menu, use [`Modify::on_context_menu`] instead,
+    /// which follows the platform's convention of opening menus on press or release.
+    #[cfg(feature = "picking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "picking")))]
+    fn on_right_click(self, f: impl Fn() + Send + Sync + 'a) -> Self
+    where
+        Self: Sized,
+    {
+        Click::observe_secondary(self, move |_| f())
+    }
+
+    /// Add a function to be called when this composable is double-clicked with the primary mouse button.
+    ///
+    /// The second click must be within 500ms and 4 logical pixels of the first.
+    /// For different thresholds see [`Modify::on_double_click_with_threshold`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct FileIcon;
+    ///
+    /// impl Compose for FileIcon {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         container(text::label("notes.txt"))
+    ///             .on_click(|| info!("Selected"))
+    ///             .on_double_click(|| info!("Opened"))
+    ///     }
+    /// }
+    /// ```
+    #[cfg(feature = "picking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "picking")))]
+    fn on_double_click(self, f: impl Fn() + Send + Sync + 'a) -> Self
+    where
+        Self: Sized,
+    {
+        self.on_double_click_with_threshold(std::time::Duration::from_millis(500), 4., f)
+    }
+
+    /// Add a function to be called when this composable is double-clicked with the primary mouse button,
+    /// with the second click within `max_delay` and `max_distance` logical pixels of the first.
+    ///
+    /// For more see [`Modify::on_double_click`].
+    #[cfg(feature = "picking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "picking")))]
+    fn on_double_click_with_threshold(
+        self,
+        max_delay: std::time::Duration,
+        max_distance: f32,
+        f: impl Fn() + Send + Sync + 'a,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        // Time and position of the last click that could start a double-click.
+        let last_click = std::sync::Mutex::new(None);
+
+        self.observe(move |trigger: Trigger<Pointer<Click>>| {
+            if trigger.event.button != PointerButton::Primary {
+                return;
+            }
+
+            let now = std::time::Instant::now();
+            let position = trigger.pointer_location.position;
+
+            let mut last_click = last_click.lock().unwrap();
+            let is_double_click = last_click.is_some_and(|(time, last_position)| {
+                now.duration_since(time) <= max_delay
+                    && position.distance(last_position) <= max_distance
+            });
+
+            if is_double_click {
+                // Start over, so a triple-click is one double-click.
+                *last_click = None;
+                f();
+            } else {
+                *last_click = Some((now, position));
+            }
+        })
+    }
+
+    /// Add a function to be called when a context menu is requested on this composable,
+    /// with the pointer position in logical pixels.
+    ///
+    /// This follows the platform convention of opening menus when the secondary mouse button is released on Windows,
+    /// and when it is pressed on other platforms.
+    /// For other actions on the secondary mouse button, use [`Modify::on_right_click`],
+    /// which is always called on a complete click.
+    #[cfg(feature = "picking")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "picking")))]
+    fn on_context_menu(self, f: impl Fn((f32, f32)) + Send + Sync + 'a) -> Self
+    where
+        Self: Sized,
+    {
+        if cfg!(target_os = "windows") {
+            Up::observe_secondary(self, f)
+        } else {
+            Down::observe_secondary(self, f)
+        }
+    }
+}
+
+/// Pointer event that can be observed for the secondary mouse button.
+#[cfg(feature = "picking")]
+trait SecondaryButtonEvent {
+    /// Observe this event from the secondary mouse button on `modify`,
+    /// calling `f` with the pointer position in logical pixels.
+    fn observe_secondary<'a, M>(modify: M, f: impl Fn((f32, f32)) + Send + Sync + 'a) -> M
+    where
+        M: Modify<'a>;
+}
+
+#[cfg(feature = "picking")]
+macro_rules! impl_secondary_button_event {
+    ($($t:ty),*) => {
+        $(
+            impl SecondaryButtonEvent for $t {
+                fn observe_secondary<'a, M>(modify: M, f: impl Fn((f32, f32)) + Send + Sync + 'a) -> M
+                where
+                    M: Modify<'a>,
+                {
+                    modify.observe(move |trigger: Trigger<Pointer<$t>>| {
+                        if trigger.event.button == PointerButton::Secondary {
+                            let position = trigger.pointer_location.position;
+                            f((position.x, position.y))
+                        }
+                    })
+                }
+            }
+        )*
+    };
 }
+
+#[cfg(feature = "picking")]
+impl_secondary_button_event!(Click, Down, Up);
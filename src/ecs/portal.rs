@@ -0,0 +1,67 @@
+use super::SpawnContext;
+use crate::{compose::Compose, data::Data, use_provider, Scope, Signal};
+use bevy_ecs::entity::Entity;
+use std::{cell::RefCell, collections::BTreeSet};
+
+/// Create a portal composable that spawns its content as children of the `target` entity.
+///
+/// The content is still composed as a child of this portal,
+/// so it keeps its state and receives context from its logical parents,
+/// while its entities are placed under `target` (e.g. an overlay node at the root of the window).
+/// This is useful for modals and tooltips that should be drawn on top of the rest of the UI.
+///
+/// The target is read once on the first composition, later changes are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::portal, prelude::*};
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Menu {
+///     overlay: Entity,
+/// }
+///
+/// impl Compose for Menu {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_open = use_mut(&cx, || false);
+///
+///         spawn(Node::default())
+///             .observe(move |_: Trigger<Pointer<Click>>| SignalMut::update(is_open, |x| *x = !*x))
+///             .content((
+///                 spawn(Text::new("Menu")),
+///                 if *is_open {
+///                     Some(portal(cx.me().overlay, spawn(Text::new("Menu items"))))
+///                 } else {
+///                     None
+///                 },
+///             ))
+///     }
+/// }
+/// ```
+pub fn portal<C: Compose>(target: Entity, content: C) -> Portal<C> {
+    Portal { target, content }
+}
+
+/// Portal composable.
+///
+/// For more see [`portal`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct Portal<C> {
+    target: Entity,
+    content: C,
+}
+
+unsafe impl<C: Data> Data for Portal<C> {}
+
+impl<C: Compose> Compose for Portal<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        use_provider(&cx, || SpawnContext {
+            parent_entity: cx.me().target,
+            keys: RefCell::new(BTreeSet::new()),
+        });
+
+        unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+    }
+}
@@ -0,0 +1,201 @@
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::KeyboardInput,
+    mouse::{MouseButtonInput, MouseWheel},
+};
+use bevy_time::Time;
+use bevy_window::{CursorMoved, WindowResized};
+use std::time::Duration;
+
+/// Input event that can be recorded with an [`EventRecorder`].
+#[derive(Clone, Debug)]
+pub enum InputEvent {
+    /// Cursor moved event.
+    CursorMoved(CursorMoved),
+
+    /// Mouse button event.
+    MouseButton(MouseButtonInput),
+
+    /// Mouse wheel event.
+    MouseWheel(MouseWheel),
+
+    /// Keyboard event.
+    Keyboard(KeyboardInput),
+
+    /// Window resized event.
+    WindowResized(WindowResized),
+}
+
+/// Input event recorded at a time relative to the start of its recording.
+#[derive(Clone, Debug)]
+pub struct RecordedEvent {
+    /// Time since the start of the recording.
+    pub time: Duration,
+
+    /// Recorded input event.
+    pub event: InputEvent,
+}
+
+/// Recorded sequence of input events.
+///
+/// This can be created with [`EventRecorder`] and replayed with [`EventReplay`].
+#[derive(Clone, Debug, Default)]
+pub struct EventRecording {
+    /// Recorded events, ordered by time.
+    pub events: Vec<RecordedEvent>,
+}
+
+/// Recorder for input events.
+///
+/// While recording, all pointer, keyboard, and resize events are stored with their timestamps.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::{EventRecorder, EventReplay}, prelude::*};
+/// use bevy::prelude::*;
+///
+/// fn start(mut recorder: ResMut<EventRecorder>) {
+///     recorder.start();
+/// }
+///
+/// fn replay(mut commands: Commands, mut recorder: ResMut<EventRecorder>) {
+///     let recording = recorder.stop();
+///     commands.insert_resource(EventReplay::new(recording));
+/// }
+/// ```
+#[derive(Default, Resource)]
+pub struct EventRecorder {
+    is_recording: bool,
+    start: Option<Duration>,
+    recording: EventRecording,
+}
+
+impl EventRecorder {
+    /// Start a new recording, discarding any previously recorded events.
+    pub fn start(&mut self) {
+        self.is_recording = true;
+        self.start = None;
+        self.recording = EventRecording::default();
+    }
+
+    /// Stop recording and return the recorded events.
+    pub fn stop(&mut self) -> EventRecording {
+        self.is_recording = false;
+        self.start = None;
+        std::mem::take(&mut self.recording)
+    }
+
+    /// Returns `true` if this recorder is currently recording.
+    pub fn is_recording(&self) -> bool {
+        self.is_recording
+    }
+}
+
+/// Replay of an [`EventRecording`].
+///
+/// When inserted as a resource, recorded events are re-sent as regular events at their recorded times,
+/// so they are routed the same way as live input.
+/// This resource is removed once every event has been replayed.
+#[derive(Resource)]
+pub struct EventReplay {
+    recording: EventRecording,
+    start: Option<Duration>,
+    idx: usize,
+}
+
+impl EventReplay {
+    /// Create a new replay of a recording.
+    pub fn new(recording: EventRecording) -> Self {
+        Self {
+            recording,
+            start: None,
+            idx: 0,
+        }
+    }
+
+    /// Returns `true` if every event in this replay has been sent.
+    pub fn is_finished(&self) -> bool {
+        self.idx >= self.recording.events.len()
+    }
+}
+
+/// Returns `true` if the [`EventRecorder`] is recording.
+pub(crate) fn is_recording(recorder: Option<Res<EventRecorder>>) -> bool {
+    recorder.is_some_and(|recorder| recorder.is_recording)
+}
+
+pub(crate) fn record_events(
+    mut recorder: ResMut<EventRecorder>,
+    time: Res<Time>,
+    mut cursor_moved: EventReader<CursorMoved>,
+    mut mouse_button: EventReader<MouseButtonInput>,
+    mut mouse_wheel: EventReader<MouseWheel>,
+    mut keyboard: EventReader<KeyboardInput>,
+    mut window_resized: EventReader<WindowResized>,
+) {
+    let start = *recorder.start.get_or_insert(time.elapsed());
+    let time = time.elapsed() - start;
+
+    let events = cursor_moved
+        .read()
+        .cloned()
+        .map(InputEvent::CursorMoved)
+        .chain(mouse_button.read().cloned().map(InputEvent::MouseButton))
+        .chain(mouse_wheel.read().cloned().map(InputEvent::MouseWheel))
+        .chain(keyboard.read().cloned().map(InputEvent::Keyboard))
+        .chain(
+            window_resized
+                .read()
+                .cloned()
+                .map(InputEvent::WindowResized),
+        )
+        .map(|event| RecordedEvent { time, event });
+
+    recorder.recording.events.extend(events);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn replay_events(
+    mut commands: Commands,
+    mut replay: ResMut<EventReplay>,
+    time: Res<Time>,
+    mut cursor_moved: EventWriter<CursorMoved>,
+    mut mouse_button: EventWriter<MouseButtonInput>,
+    mut mouse_wheel: EventWriter<MouseWheel>,
+    mut keyboard: EventWriter<KeyboardInput>,
+    mut window_resized: EventWriter<WindowResized>,
+) {
+    let start = *replay.start.get_or_insert(time.elapsed());
+    let elapsed = time.elapsed() - start;
+
+    while let Some(recorded) = replay.recording.events.get(replay.idx) {
+        if recorded.time > elapsed {
+            break;
+        }
+
+        match recorded.event.clone() {
+            InputEvent::CursorMoved(event) => {
+                cursor_moved.send(event);
+            }
+            InputEvent::MouseButton(event) => {
+                mouse_button.send(event);
+            }
+            InputEvent::MouseWheel(event) => {
+                mouse_wheel.send(event);
+            }
+            InputEvent::Keyboard(event) => {
+                keyboard.send(event);
+            }
+            InputEvent::WindowResized(event) => {
+                window_resized.send(event);
+            }
+        }
+
+        replay.idx += 1;
+    }
+
+    if replay.is_finished() {
+        commands.remove_resource::<EventReplay>();
+    }
+}
@@ -0,0 +1,194 @@
+use super::use_world;
+use crate::{use_ref, ScopeState};
+use bevy_ecs::prelude::*;
+use bevy_input::{
+    keyboard::{KeyCode, KeyboardInput},
+    ButtonInput, ButtonState,
+};
+use bevy_time::Time;
+use std::{cell::Cell, time::Duration};
+
+/// Combination of a key and modifier keys.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::ecs::KeyCombo;
+/// use bevy::prelude::*;
+///
+/// // Ctrl + S
+/// let save = KeyCombo::new(KeyCode::KeyS).ctrl();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct KeyCombo {
+    /// Key code of this combination.
+    pub key: KeyCode,
+
+    /// `true` if either control key must be pressed.
+    pub ctrl: bool,
+
+    /// `true` if either shift key must be pressed.
+    pub shift: bool,
+
+    /// `true` if either alt key must be pressed.
+    pub alt: bool,
+
+    /// `true` if either super (e.g. command or windows) key must be pressed.
+    pub super_key: bool,
+}
+
+impl KeyCombo {
+    /// Create a new key combination of `key` without modifiers.
+    pub fn new(key: KeyCode) -> Self {
+        Self {
+            key,
+            ctrl: false,
+            shift: false,
+            alt: false,
+            super_key: false,
+        }
+    }
+
+    /// Require a control key.
+    pub fn ctrl(mut self) -> Self {
+        self.ctrl = true;
+        self
+    }
+
+    /// Require a shift key.
+    pub fn shift(mut self) -> Self {
+        self.shift = true;
+        self
+    }
+
+    /// Require an alt key.
+    pub fn alt(mut self) -> Self {
+        self.alt = true;
+        self
+    }
+
+    /// Require a super key.
+    pub fn super_key(mut self) -> Self {
+        self.super_key = true;
+        self
+    }
+
+    fn from_input(key: KeyCode, input: &ButtonInput<KeyCode>) -> Self {
+        Self {
+            key,
+            ctrl: input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            shift: input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            alt: input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+            super_key: input.any_pressed([KeyCode::SuperLeft, KeyCode::SuperRight]),
+        }
+    }
+}
+
+impl From<KeyCode> for KeyCombo {
+    fn from(key: KeyCode) -> Self {
+        Self::new(key)
+    }
+}
+
+fn is_modifier(key: KeyCode) -> bool {
+    matches!(
+        key,
+        KeyCode::ControlLeft
+            | KeyCode::ControlRight
+            | KeyCode::ShiftLeft
+            | KeyCode::ShiftRight
+            | KeyCode::AltLeft
+            | KeyCode::AltRight
+            | KeyCode::SuperLeft
+            | KeyCode::SuperRight
+    )
+}
+
+/// Use a sequence of key combinations, calling `f` when the full sequence is pressed.
+///
+/// Each combination must be pressed within one second of the last.
+/// For a different timeout see [`use_sequence_with_timeout`].
+///
+/// The sequence is reset when it times out or a different combination is pressed.
+/// Modifier keys on their own never reset the sequence.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::KeyCombo, prelude::*};
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Ctrl + X, Ctrl + S
+///         use_sequence(
+///             &cx,
+///             &[
+///                 KeyCombo::new(KeyCode::KeyX).ctrl(),
+///                 KeyCombo::new(KeyCode::KeyS).ctrl(),
+///             ],
+///             || info!("Saved!"),
+///         );
+///     }
+/// }
+/// ```
+pub fn use_sequence<'a>(cx: ScopeState<'a>, sequence: &[KeyCombo], f: impl Fn() + 'a) {
+    use_sequence_with_timeout(cx, sequence, Duration::from_secs(1), f)
+}
+
+/// Use a sequence of key combinations with a `timeout` between each combination,
+/// calling `f` when the full sequence is pressed.
+///
+/// For more see [`use_sequence`].
+pub fn use_sequence_with_timeout<'a>(
+    cx: ScopeState<'a>,
+    sequence: &[KeyCombo],
+    timeout: Duration,
+    f: impl Fn() + 'a,
+) {
+    // Number of matched combinations, and the time of the last match.
+    let progress = use_ref(cx, || Cell::new((0, 0.)));
+
+    let sequence = sequence.to_vec();
+    use_world(
+        cx,
+        move |mut keyboard_input_events: EventReader<KeyboardInput>,
+              keyboard_input: Res<ButtonInput<KeyCode>>,
+              time: Res<Time>| {
+            let now = time.elapsed_secs();
+
+            for event in keyboard_input_events.read() {
+                if event.state != ButtonState::Pressed
+                    || event.repeat
+                    || is_modifier(event.key_code)
+                {
+                    continue;
+                }
+
+                let (mut idx, last) = progress.get();
+                if idx > 0 && now - last > timeout.as_secs_f32() {
+                    idx = 0;
+                }
+
+                let combo = KeyCombo::from_input(event.key_code, &keyboard_input);
+                idx = if sequence.get(idx) == Some(&combo) {
+                    idx + 1
+                } else if sequence.first() == Some(&combo) {
+                    1
+                } else {
+                    0
+                };
+
+                if !sequence.is_empty() && idx == sequence.len() {
+                    f();
+                    idx = 0;
+                }
+
+                progress.set((idx, now));
+            }
+        },
+    );
+}
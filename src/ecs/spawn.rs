@@ -1,4 +1,7 @@
-use super::{use_bundle_inner, RuntimeContext, SpawnContext, SystemParamFunction};
+use super::{
+    diagnostics::{self, RecomposeCount},
+    use_bundle_inner, RuntimeContext, SpawnContext, SystemParamFunction,
+};
 use crate::{
     compose::Compose, composer::Runtime, data::Data, use_context, use_drop, use_provider, use_ref,
     Scope, Signal,
@@ -175,6 +178,21 @@ impl<C: Compose> Compose for Spawn<'_, C> {
                 f(world.entity_mut(entity.unwrap()));
             }
 
+            if diagnostics::is_overlay_enabled(world) {
+                world
+                    .entity_mut(entity.unwrap())
+                    .insert(RecomposeCount(cx.generation.get()));
+            }
+
+            #[cfg(feature = "ui")]
+            if diagnostics::is_layout_overlay_enabled(world) {
+                if let Some(name) = diagnostics::composable_name(&rt) {
+                    world
+                        .entity_mut(entity.unwrap())
+                        .insert(diagnostics::ComposableName(name));
+                }
+            }
+
             if is_initial.get() {
                 for f in &cx.me().on_spawn {
                     f(world.entity_mut(entity.unwrap()));
@@ -0,0 +1,51 @@
+use super::use_world;
+use crate::{use_ref, ScopeState};
+use bevy_ecs::prelude::*;
+use bevy_time::{Time, Timer, TimerMode};
+use std::{cell::RefCell, time::Duration};
+
+/// Use an interval, calling `f` every `period`.
+///
+/// The interval is measured with the [`Time`] resource, so it follows Bevy's virtual time (e.g. it pauses with the app).
+/// If more than one period elapses in a single frame, `f` is called once for each period.
+/// The interval is cancelled when the current composable is removed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+/// use std::time::Duration;
+///
+/// #[derive(Data)]
+/// struct Clock;
+///
+/// impl Compose for Clock {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let seconds = use_mut(&cx, || 0);
+///
+///         use_interval(&cx, Duration::from_secs(1), move || {
+///             SignalMut::update(seconds, |seconds| *seconds += 1)
+///         });
+///
+///         spawn(Text::new(format!("{} seconds", *seconds)))
+///     }
+/// }
+/// ```
+pub fn use_interval<'a>(cx: ScopeState<'a>, period: Duration, mut f: impl FnMut() + 'a) {
+    let timer = use_ref(cx, || {
+        RefCell::new(Timer::new(period, TimerMode::Repeating))
+    });
+
+    use_world(cx, move |time: Res<Time>| {
+        let mut timer = timer.borrow_mut();
+        if timer.duration() != period {
+            timer.set_duration(period);
+        }
+
+        timer.tick(time.delta());
+        for _ in 0..timer.times_finished_this_tick() {
+            f();
+        }
+    });
+}
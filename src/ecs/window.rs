@@ -0,0 +1,875 @@
+use super::{use_world, RuntimeContext};
+use crate::{data::Data, use_mut, use_ref, ScopeState, Signal, SignalMut};
+
+#[cfg(feature = "ui")]
+use super::SpawnContext;
+#[cfg(feature = "ui")]
+use crate::{compose::Compose, use_drop, use_provider, Scope};
+use bevy_ecs::{
+    event::{EventCursor, Events},
+    prelude::*,
+};
+use bevy_time::Time;
+use bevy_window::{
+    FileDragAndDrop, Monitor, MonitorSelection, PrimaryMonitor, PrimaryWindow, RawHandleWrapper,
+    ThreadLockedRawWindowHandleWrapper, Window, WindowCloseRequested, WindowMode, WindowPosition,
+    WindowResized,
+};
+use bevy_winit::WinitWindows;
+use std::{
+    cell::{Cell, RefCell},
+    ops::Deref,
+    path::Path,
+    time::Duration,
+};
+
+#[cfg(feature = "ui")]
+use bevy_color::Color;
+#[cfg(feature = "ui")]
+use bevy_core_pipeline::core_2d::Camera2d;
+#[cfg(feature = "ui")]
+use bevy_render::{
+    camera::{Camera, ClearColorConfig, RenderTarget},
+    view::Msaa,
+};
+#[cfg(feature = "ui")]
+use bevy_ui::{prelude::*, ComputedNode};
+#[cfg(feature = "ui")]
+use bevy_window::WindowRef;
+#[cfg(feature = "ui")]
+use std::collections::BTreeSet;
+
+/// Use the current position of the cursor in the primary window, in logical pixels.
+///
+/// This will re-compose the current composable at most once per frame, when the cursor moves, enters, or leaves the window.
+/// If the cursor leaves the window, the last known position is returned
+/// (to handle the cursor leaving the window see [`use_cursor`], which this is built on).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Spotlight;
+///
+/// impl Compose for Spotlight {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (x, y) = use_pointer_position(&cx);
+///
+///         spawn(Node {
+///             position_type: PositionType::Absolute,
+///             left: Val::Px(x),
+///             top: Val::Px(y),
+///             ..default()
+///         })
+///     }
+/// }
+/// ```
+pub fn use_pointer_position(cx: ScopeState) -> (f32, f32) {
+    let cursor = use_cursor(cx);
+
+    let last_position = use_ref(cx, || Cell::new((0., 0.)));
+    if let Some(position) = cursor {
+        last_position.set(position);
+    }
+
+    last_position.get()
+}
+
+/// Use the current position of the cursor in the primary window, in logical pixels,
+/// or `None` if the cursor is outside of the window.
+///
+/// This will re-compose the current composable at most once per frame, when the cursor moves, enters, or leaves the window.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Crosshair;
+///
+/// impl Compose for Crosshair {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let display = if use_cursor(&cx).is_some() {
+///             Display::Flex
+///         } else {
+///             Display::None
+///         };
+///
+///         spawn((Text::new("+"), Node { display, ..default() }))
+///     }
+/// }
+/// ```
+pub fn use_cursor(cx: ScopeState) -> Option<(f32, f32)> {
+    let position = use_mut(cx, || None);
+
+    use_world(
+        cx,
+        move |window_query: Query<&Window, With<PrimaryWindow>>| {
+            let cursor = window_query
+                .get_single()
+                .ok()
+                .and_then(|window| window.cursor_position());
+
+            SignalMut::set_if_neq(position, cursor.map(|cursor| (cursor.x, cursor.y)));
+        },
+    );
+
+    *position
+}
+
+/// Use the size of the primary window, in logical pixels.
+///
+/// This will re-compose the current composable when the window is resized.
+/// Resize events are debounced, so the size is only updated once the window
+/// has stopped resizing for 100ms.
+/// For a different delay see [`use_window_size_with_debounce`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Layout;
+///
+/// impl Compose for Layout {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (width, _height) = use_window_size(&cx);
+///
+///         let label = if width < 600 { "Compact" } else { "Expanded" };
+///         spawn(Text::new(label))
+///     }
+/// }
+/// ```
+pub fn use_window_size(cx: ScopeState) -> (u32, u32) {
+    use_window_size_with_debounce(cx, Duration::from_millis(100))
+}
+
+/// Use the size of the primary window, in logical pixels,
+/// updating only once the window has stopped resizing for `debounce`.
+///
+/// For more see [`use_window_size`].
+pub fn use_window_size_with_debounce(cx: ScopeState, debounce: Duration) -> (u32, u32) {
+    let size = use_mut(cx, || (0, 0));
+
+    // Time of the last unhandled resize event, or `None` if the size is up to date.
+    // This starts infinitely far in the past so the initial size is read immediately.
+    let last_resize = use_ref(cx, || Cell::new(Some(f32::NEG_INFINITY)));
+
+    use_world(
+        cx,
+        move |mut resized_events: EventReader<WindowResized>,
+              window_query: Query<(Entity, &Window), With<PrimaryWindow>>,
+              time: Res<Time>| {
+            let Ok((entity, window)) = window_query.get_single() else {
+                return;
+            };
+            let now = time.elapsed_secs();
+
+            if resized_events.read().any(|event| event.window == entity) {
+                last_resize.set(Some(now));
+            }
+
+            if let Some(last) = last_resize.get() {
+                if now - last >= debounce.as_secs_f32() {
+                    last_resize.set(None);
+
+                    let new_size = (window.width() as u32, window.height() as u32);
+                    SignalMut::set_if_neq(size, new_size);
+                }
+            }
+        },
+    );
+
+    *size
+}
+
+/// Information about a connected monitor.
+///
+/// For more see [`use_monitors`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MonitorInfo {
+    /// Entity of this monitor's [`Monitor`] component.
+    pub entity: Entity,
+
+    /// Name of this monitor.
+    pub name: Option<String>,
+
+    /// Width of this monitor in physical pixels.
+    pub physical_width: u32,
+
+    /// Height of this monitor in physical pixels.
+    pub physical_height: u32,
+
+    /// Position of this monitor in physical pixels.
+    pub physical_position: (i32, i32),
+
+    /// Scale factor of this monitor.
+    pub scale_factor: f64,
+
+    /// Refresh rate of this monitor in millihertz.
+    pub refresh_rate_millihertz: Option<u32>,
+
+    /// `true` if this is the primary monitor.
+    pub is_primary: bool,
+
+    /// `true` if the primary window is currently positioned on this monitor.
+    pub is_current: bool,
+}
+
+/// Use the currently connected monitors.
+///
+/// This will re-compose the current composable when a monitor is connected or disconnected,
+/// or when the primary window moves to a different monitor.
+///
+/// To move a window to a specific monitor, set its [`WindowPosition`] (e.g. with [`use_world`]).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct RefreshRate;
+///
+/// impl Compose for RefreshRate {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let monitors = use_monitors(&cx);
+///
+///         let hz = monitors
+///             .iter()
+///             .find(|monitor| monitor.is_current)
+///             .and_then(|monitor| monitor.refresh_rate_millihertz)
+///             .map(|millihertz| millihertz / 1000);
+///
+///         spawn(Text::new(format!("Refresh rate: {:?}", hz)))
+///     }
+/// }
+/// ```
+pub fn use_monitors(cx: ScopeState<'_>) -> Signal<'_, Vec<MonitorInfo>> {
+    let monitors = use_mut(cx, Vec::new);
+
+    use_world(
+        cx,
+        move |monitor_query: Query<(Entity, &Monitor, Has<PrimaryMonitor>)>,
+              window_query: Query<&Window, With<PrimaryWindow>>| {
+            let window_position = match window_query.get_single().map(|window| window.position) {
+                Ok(WindowPosition::At(position)) => Some(position),
+                _ => None,
+            };
+
+            let new_monitors: Vec<_> = monitor_query
+                .iter()
+                .map(|(entity, monitor, is_primary)| {
+                    let position = monitor.physical_position;
+                    let is_current = window_position.is_some_and(|window_position| {
+                        window_position.x >= position.x
+                            && window_position.y >= position.y
+                            && window_position.x < position.x + monitor.physical_width as i32
+                            && window_position.y < position.y + monitor.physical_height as i32
+                    });
+
+                    MonitorInfo {
+                        entity,
+                        name: monitor.name.clone(),
+                        physical_width: monitor.physical_width,
+                        physical_height: monitor.physical_height,
+                        physical_position: (position.x, position.y),
+                        scale_factor: monitor.scale_factor,
+                        refresh_rate_millihertz: monitor.refresh_rate_millihertz,
+                        is_primary,
+                        is_current,
+                    }
+                })
+                .collect();
+
+            SignalMut::set_if_neq(monitors, new_monitors);
+        },
+    );
+
+    SignalMut::as_ref(monitors)
+}
+
+/// State of a window.
+///
+/// For more see [`use_window_state`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WindowState {
+    /// `true` if the window is maximized.
+    pub is_maximized: bool,
+
+    /// `true` if the window is minimized.
+    pub is_minimized: bool,
+
+    /// `true` if the window is fullscreen.
+    pub is_fullscreen: bool,
+}
+
+/// Hook for [`use_window_state`].
+pub struct UseWindowState<'a> {
+    state: Signal<'a, WindowState>,
+}
+
+impl UseWindowState<'_> {
+    /// Queue a request to maximize or restore the primary window.
+    pub fn set_maximized(_me: Self, is_maximized: bool) {
+        queue_window_update(move |window| window.set_maximized(is_maximized));
+    }
+
+    /// Queue a request to minimize or restore the primary window.
+    pub fn set_minimized(_me: Self, is_minimized: bool) {
+        queue_window_update(move |window| window.set_minimized(is_minimized));
+    }
+
+    /// Queue a request to enter or exit borderless fullscreen on the primary window's current monitor.
+    pub fn set_fullscreen(_me: Self, is_fullscreen: bool) {
+        queue_window_update(move |window| {
+            window.mode = if is_fullscreen {
+                WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+            } else {
+                WindowMode::Windowed
+            };
+        });
+    }
+}
+
+impl Clone for UseWindowState<'_> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl Copy for UseWindowState<'_> {}
+
+impl Deref for UseWindowState<'_> {
+    type Target = WindowState;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+unsafe impl Send for UseWindowState<'_> {}
+
+unsafe impl Sync for UseWindowState<'_> {}
+
+unsafe impl Data for UseWindowState<'_> {}
+
+fn queue_window_update(f: impl FnOnce(&mut Window) + Send + 'static) {
+    let commands = RuntimeContext::current().inner.borrow().commands.clone();
+    commands.borrow_mut().push(move |world: &mut World| {
+        let mut window_query = world.query_filtered::<&mut Window, With<PrimaryWindow>>();
+        if let Ok(mut window) = window_query.get_single_mut(world) {
+            f(&mut window);
+        }
+    });
+}
+
+/// Use the maximized, minimized, and fullscreen state of the primary window.
+///
+/// This will re-compose the current composable when the state changes,
+/// including changes made by the user or operating system.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct TitleBar;
+///
+/// impl Compose for TitleBar {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let window_state = use_window_state(&cx);
+///
+///         let label = if window_state.is_maximized {
+///             "Restore"
+///         } else {
+///             "Maximize"
+///         };
+///
+///         button(text::label(label)).on_click(move || {
+///             UseWindowState::set_maximized(window_state, !window_state.is_maximized)
+///         })
+///     }
+/// }
+/// ```
+pub fn use_window_state(cx: ScopeState<'_>) -> UseWindowState<'_> {
+    let state = use_mut(cx, WindowState::default);
+
+    use_world(
+        cx,
+        move |window_query: Query<(Entity, &Window), With<PrimaryWindow>>,
+              winit_windows: Option<NonSend<WinitWindows>>| {
+            let Ok((entity, window)) = window_query.get_single() else {
+                return;
+            };
+            let winit_window = winit_windows
+                .as_ref()
+                .and_then(|winit_windows| winit_windows.get_window(entity));
+
+            let new_state = WindowState {
+                is_maximized: winit_window.is_some_and(|window| window.is_maximized()),
+                is_minimized: winit_window
+                    .and_then(|window| window.is_minimized())
+                    .unwrap_or(false),
+                is_fullscreen: window.mode != WindowMode::Windowed,
+            };
+
+            SignalMut::set_if_neq(state, new_state);
+        },
+    );
+
+    UseWindowState {
+        state: SignalMut::as_ref(state),
+    }
+}
+
+/// Use a function to handle close requests of the primary window (e.g. from the window's close button).
+///
+/// If `f` returns `false`, the close request is cancelled and the window is kept open.
+/// Otherwise, the window is closed as usual (and the app exits once its
+/// [`ExitCondition`](bevy_window::ExitCondition) is met).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_saved = use_mut(&cx, || false);
+///         let is_prompt_open = use_mut(&cx, || false);
+///
+///         use_on_close(&cx, move || {
+///             if !*is_saved {
+///                 SignalMut::set(is_prompt_open, true);
+///             }
+///             *is_saved
+///         });
+///
+///         if *is_prompt_open {
+///             spawn(Text::new("You have unsaved changes."))
+///         } else {
+///             spawn(Text::new("Editor"))
+///         }
+///     }
+/// }
+/// ```
+pub fn use_on_close<'a>(cx: ScopeState<'a>, mut f: impl FnMut() -> bool + 'a) {
+    let cursor = use_ref(cx, || {
+        RefCell::new(EventCursor::<WindowCloseRequested>::default())
+    });
+
+    use_world(
+        cx,
+        move |events: Option<ResMut<Events<WindowCloseRequested>>>,
+              window_query: Query<Entity, With<PrimaryWindow>>| {
+            let (Some(mut events), Ok(primary_window)) = (events, window_query.get_single()) else {
+                return;
+            };
+
+            let mut cursor = cursor.borrow_mut();
+            let new_events: Vec<_> = cursor.read(&events).cloned().collect();
+            let kept_events: Vec<_> = new_events
+                .iter()
+                .filter(|event| event.window != primary_window || f())
+                .cloned()
+                .collect();
+
+            if kept_events.len() == new_events.len() {
+                return;
+            }
+
+            // Events can't be removed individually, so clear them and re-send the events that were kept.
+            events.drain().for_each(drop);
+            events.send_batch(kept_events);
+
+            // Skip over the re-sent events.
+            cursor.read(&events).for_each(drop);
+        },
+    );
+}
+
+/// Use files dropped onto the primary window, calling `f` with the path of each dropped file.
+///
+/// This returns `true` while files are dragged over the window,
+/// and will re-compose the current composable when files start or stop hovering over the window.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct ImageViewer;
+///
+/// impl Compose for ImageViewer {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let path = use_mut(&cx, || None);
+///
+///         let is_hovered = use_dropped_files(&cx, move |new_path| {
+///             SignalMut::set(path, Some(new_path.to_path_buf()));
+///         });
+///
+///         let color = if is_hovered {
+///             Color::srgb(0.2, 0.2, 0.5)
+///         } else {
+///             Color::BLACK
+///         };
+///
+///         let label = match &*path {
+///             Some(path) => path.display().to_string(),
+///             None => String::from("Drop an image here"),
+///         };
+///
+///         spawn((Text::new(label), BackgroundColor(color)))
+///     }
+/// }
+/// ```
+pub fn use_dropped_files<'a>(cx: ScopeState<'a>, mut f: impl FnMut(&Path) + 'a) -> bool {
+    let is_hovered = use_mut(cx, || false);
+
+    use_world(
+        cx,
+        move |mut events: EventReader<FileDragAndDrop>,
+              window_query: Query<Entity, With<PrimaryWindow>>| {
+            let Ok(primary_window) = window_query.get_single() else {
+                events.clear();
+                return;
+            };
+
+            let mut new_is_hovered = *is_hovered;
+            for event in events.read() {
+                match event {
+                    FileDragAndDrop::DroppedFile { window, path_buf } => {
+                        if *window == primary_window {
+                            f(path_buf);
+                            new_is_hovered = false;
+                        }
+                    }
+                    FileDragAndDrop::HoveredFile { window, .. } => {
+                        if *window == primary_window {
+                            new_is_hovered = true;
+                        }
+                    }
+                    FileDragAndDrop::HoveredFileCanceled { window } => {
+                        if *window == primary_window {
+                            new_is_hovered = false;
+                        }
+                    }
+                }
+            }
+
+            SignalMut::set_if_neq(is_hovered, new_is_hovered);
+        },
+    );
+
+    *is_hovered
+}
+
+/// Use the raw window and display handles of the primary window,
+/// calling `f` once per frame while the window exists.
+///
+/// This can be used to create external renderers (e.g. a custom wgpu surface) that draw into the same window.
+/// The handle is only borrowed for the duration of `f`,
+/// and anything created from it must be dropped before the window is closed.
+/// `f` is called on the main thread, so the handle can be used with APIs that require it.
+///
+/// To share Bevy's wgpu device and queue, use `RenderDevice` and `RenderQueue`
+/// from `bevy::render::renderer` with [`use_world`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::{prelude::*, window::ThreadLockedRawWindowHandleWrapper};
+/// use std::cell::Cell;
+///
+/// fn create_surface(handle: &ThreadLockedRawWindowHandleWrapper) {
+///     // Create a surface from the window's handle...
+/// }
+///
+/// #[derive(Data)]
+/// struct Viewport;
+///
+/// impl Compose for Viewport {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_created = use_ref(&cx, || Cell::new(false));
+///
+///         use_window_handle(&cx, move |handle| {
+///             if !is_created.get() {
+///                 create_surface(handle);
+///                 is_created.set(true);
+///             }
+///         });
+///     }
+/// }
+/// ```
+pub fn use_window_handle<'a>(
+    cx: ScopeState<'a>,
+    mut f: impl FnMut(&ThreadLockedRawWindowHandleWrapper) + 'a,
+) {
+    use_world(
+        cx,
+        move |window_query: Query<&RawHandleWrapper, With<PrimaryWindow>>| {
+            let Ok(wrapper) = window_query.get_single() else {
+                return;
+            };
+
+            // Safety: composition runs on the main thread.
+            let handle = unsafe { wrapper.get_handle() };
+            f(&handle);
+        },
+    );
+}
+
+/// Create a [`WindowRoot`] composable that opens a new OS window when composed.
+///
+/// Each window gets its own camera and root UI node,
+/// so its content is laid out and rendered independently of other windows.
+/// Use [`WindowRoot::content`] to set the composable content of the window,
+/// and [`WindowRoot::background`] to set its clear color.
+///
+/// The present mode (e.g. to disable vsync) is set with [`Window::present_mode`],
+/// and the antialiasing method of the window's camera with [`WindowRoot::msaa`].
+///
+/// The window is created once from `window`.
+/// Later changes to its title, mode, window level, decorations, resizability, and resize constraints
+/// are applied on re-composition, while other changes (like its resolution) are ignored so they don't override the user.
+///
+/// The content of the window is laid out in a root node that fills the window,
+/// so percentage and flex sizes resolve against the window's size
+/// (which is kept within its [`resize_constraints`](Window::resize_constraints)).
+/// When this composable is removed, the window and its content are despawned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{ecs::window, prelude::*};
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let is_saved = use_mut(&cx, || true);
+///
+///         (
+///             window(Window {
+///                 title: if *is_saved { "Editor" } else { "Editor*" }.to_string(),
+///                 ..default()
+///             })
+///             .background(Color::WHITE)
+///             .content(spawn((Text::new("Editor"), TextColor(Color::BLACK)))),
+///             window(Window {
+///                 title: String::from("Inspector"),
+///                 ..default()
+///             })
+///             .content(spawn(Text::new("Inspector"))),
+///         )
+///     }
+/// }
+/// ```
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+pub fn window(window: Window) -> WindowRoot {
+    WindowRoot {
+        window,
+        background: None,
+        msaa: Msaa::default(),
+        content: (),
+    }
+}
+
+/// Window composable.
+///
+/// For more see [`window`].
+#[cfg(feature = "ui")]
+#[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct WindowRoot<C = ()> {
+    window: Window,
+    background: Option<Color>,
+    msaa: Msaa,
+    content: C,
+}
+
+#[cfg(feature = "ui")]
+impl<C> WindowRoot<C> {
+    /// Set the background color this window is cleared to before rendering
+    /// (default: the [`ClearColor`](bevy_render::camera::ClearColor) resource).
+    pub fn background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Set the multisample antialiasing of this window (default: [`Msaa::Sample4`]).
+    pub fn msaa(mut self, msaa: Msaa) -> Self {
+        self.msaa = msaa;
+        self
+    }
+
+    /// Set the child content of this window.
+    pub fn content<C2>(self, content: C2) -> WindowRoot<C2> {
+        WindowRoot {
+            window: self.window,
+            background: self.background,
+            msaa: self.msaa,
+            content,
+        }
+    }
+}
+
+#[cfg(feature = "ui")]
+unsafe impl<C: Data> Data for WindowRoot<C> {}
+
+#[cfg(feature = "ui")]
+impl<C: Compose> Compose for WindowRoot<C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let entities = *use_ref(&cx, || {
+            let world = unsafe { RuntimeContext::current().world_mut() };
+
+            let window_entity = world.spawn(cx.me().window.clone()).id();
+            let camera_entity = world
+                .spawn((
+                    Camera2d,
+                    Camera {
+                        target: RenderTarget::Window(WindowRef::Entity(window_entity)),
+                        ..Default::default()
+                    },
+                    cx.me().msaa,
+                ))
+                .id();
+            let root_entity = world
+                .spawn((
+                    Node {
+                        width: Val::Percent(100.),
+                        height: Val::Percent(100.),
+                        flex_direction: FlexDirection::Column,
+                        ..Default::default()
+                    },
+                    TargetCamera(camera_entity),
+                ))
+                .id();
+
+            [window_entity, camera_entity, root_entity]
+        });
+
+        let world = unsafe { RuntimeContext::current().world_mut() };
+        if let Some(mut camera) = world.get_mut::<Camera>(entities[1]) {
+            let background = cx.me().background;
+            let is_changed = match (&camera.clear_color, background) {
+                (ClearColorConfig::Default, None) => false,
+                (ClearColorConfig::Custom(color), Some(background)) => *color != background,
+                _ => true,
+            };
+
+            if is_changed {
+                camera.clear_color = background.map(ClearColorConfig::Custom).unwrap_or_default();
+            }
+        }
+
+        if let Some(mut msaa) = world.get_mut::<Msaa>(entities[1]) {
+            msaa.set_if_neq(cx.me().msaa);
+        }
+
+        if let Some(mut window) = world.get_mut::<Window>(entities[0]) {
+            let new_window = &cx.me().window;
+
+            if window.title != new_window.title {
+                window.title.clone_from(&new_window.title);
+            }
+            if window.mode != new_window.mode {
+                window.mode = new_window.mode;
+            }
+            if window.window_level != new_window.window_level {
+                window.window_level = new_window.window_level;
+            }
+            if window.decorations != new_window.decorations {
+                window.decorations = new_window.decorations;
+            }
+            if window.resizable != new_window.resizable {
+                window.resizable = new_window.resizable;
+            }
+            if window.resize_constraints != new_window.resize_constraints {
+                window.resize_constraints = new_window.resize_constraints;
+            }
+        }
+
+        use_provider(&cx, || SpawnContext {
+            parent_entity: entities[2],
+            keys: RefCell::new(BTreeSet::new()),
+        });
+
+        use_drop(&cx, move || {
+            let world = unsafe { RuntimeContext::current().world_mut() };
+            for entity in entities.into_iter().rev() {
+                world.try_despawn(entity);
+            }
+        });
+
+        unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) }
+    }
+}
+
+/// Marker for a composition's target node that should size the primary window to its content.
+///
+/// This is inserted by [`Composition::with_size_to_content`](super::Composition::with_size_to_content).
+#[cfg(feature = "ui")]
+#[derive(Component)]
+pub(crate) struct SizeToContent;
+
+/// Resize the primary window to the first non-empty layout of each [`SizeToContent`] node.
+///
+/// The size is clamped to the window's resize constraints and the primary monitor,
+/// and the window is made visible once it has been resized.
+#[cfg(feature = "ui")]
+pub(crate) fn size_to_content(
+    mut commands: Commands,
+    node_query: Query<(Entity, &ComputedNode), With<SizeToContent>>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+    monitor_query: Query<&Monitor, With<PrimaryMonitor>>,
+) {
+    let Ok(mut window) = window_query.get_single_mut() else {
+        return;
+    };
+
+    for (entity, computed_node) in &node_query {
+        if computed_node.is_empty() {
+            continue;
+        }
+
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+
+        // Correct constraints with a minimum above their maximum, which `clamp` would panic on.
+        let constraints = window.resize_constraints.check_constraints();
+
+        let mut width = size.x.clamp(constraints.min_width, constraints.max_width);
+        let mut height = size.y.clamp(constraints.min_height, constraints.max_height);
+
+        if let Ok(monitor) = monitor_query.get_single() {
+            let scale_factor = monitor.scale_factor as f32;
+            width = width.min(monitor.physical_width as f32 / scale_factor);
+            height = height.min(monitor.physical_height as f32 / scale_factor);
+        }
+
+        window.resolution.set(width, height);
+        window.visible = true;
+
+        commands.entity(entity).remove::<SizeToContent>();
+    }
+}
@@ -1,10 +1,25 @@
 use alloc::{rc::Rc, sync::Arc};
-use core::{future::Future, pin::Pin};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures::channel::oneshot;
 
 /// Executor for async tasks.
+///
+/// This is implemented for the Tokio runtime with the `rt` feature,
+/// and can be implemented for any other runtime that can spawn `Send` futures.
 pub trait Executor {
     /// Spawn a boxed future on this executor.
     fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>);
+
+    /// Run a blocking function on a thread where blocking is acceptable.
+    ///
+    /// By default this spawns a new thread for each function.
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+        std::thread::spawn(f);
+    }
 }
 
 #[cfg(feature = "rt")]
@@ -13,6 +28,10 @@ impl Executor for tokio::runtime::Runtime {
     fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
         self.spawn(future);
     }
+
+    fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+        self.spawn_blocking(f);
+    }
 }
 
 macro_rules! impl_executor {
@@ -22,6 +41,10 @@ macro_rules! impl_executor {
                 fn spawn(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
                     (**self).spawn(future);
                 }
+
+                fn spawn_blocking(&self, f: Box<dyn FnOnce() + Send>) {
+                    (**self).spawn_blocking(f);
+                }
             }
         )*
     };
@@ -61,4 +84,68 @@ impl ExecutorContext {
     pub fn spawn_boxed(&self, future: Pin<Box<dyn Future<Output = ()> + Send>>) {
         self.executor.spawn(future);
     }
+
+    /// Run a blocking function off of the composition thread,
+    /// returning a future that resolves to its output.
+    ///
+    /// Heavy synchronous work (like parsing or decoding images) can be moved here to keep composition responsive.
+    /// The output can then be applied to the composition by awaiting this future in a task,
+    /// like [`use_local_task`](crate::use_local_task) or [`use_task`](crate::use_task).
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::{executor::ExecutorContext, prelude::*};
+    ///
+    /// #[derive(Data)]
+    /// struct Document;
+    ///
+    /// impl Compose for Document {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let word_count = use_mut(&cx, || None);
+    ///
+    ///         let executor_cx = use_context::<ExecutorContext>(&cx).unwrap().clone();
+    ///         use_local_task(&cx, move || async move {
+    ///             let count = executor_cx
+    ///                 .spawn_blocking(|| {
+    ///                     let text = std::fs::read_to_string("document.txt").unwrap();
+    ///                     text.split_whitespace().count()
+    ///                 })
+    ///                 .await;
+    ///
+    ///             SignalMut::set(word_count, Some(count));
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn spawn_blocking<T, F>(&self, f: F) -> BlockingTask<T>
+    where
+        T: Send + 'static,
+        F: FnOnce() -> T + Send + 'static,
+    {
+        let (tx, rx) = oneshot::channel();
+        self.executor.spawn_blocking(Box::new(move || {
+            let _ = tx.send(f());
+        }));
+
+        BlockingTask { rx }
+    }
+}
+
+/// Future for the output of a blocking function.
+///
+/// For more see [`ExecutorContext::spawn_blocking`].
+#[must_use = "Futures do nothing unless you `.await` or poll them"]
+pub struct BlockingTask<T> {
+    rx: oneshot::Receiver<T>,
+}
+
+impl<T> Future for BlockingTask<T> {
+    type Output = T;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        Pin::new(&mut self.rx)
+            .poll(cx)
+            .map(|res| res.expect("Blocking task panicked."))
+    }
 }
@@ -111,19 +111,25 @@
 //! - `animation`: Enables the `animation` module for animating values from the [Bevy](https://crates.io/crates/bevy) ECS.
 //!   (enables the `ecs` feature).
 //! - `ecs`: Enables the `ecs` module for bindings to the [Bevy](https://crates.io/crates/bevy) ECS.
-//! - `executor`: Enables the `executor` module for multi-threaded tasks.
+//! - `executor`: Enables the `executor` module for multi-threaded tasks on any async runtime.
 //! - `material`: Enables the `material` module for Material UI (enables the `ecs` and `ui` features).
 //! - `picking`: Enables support for picking event handlers with `Modify` (requires the `ecs` feature).
 //! - `rt` Enables support for the [Tokio](https://crates.io/crates/tokio) runtime with the Executor trait.
 //!   (enables the `executor` feature).
-//! - `tracing`: Enables the logging through the `tracing` crate.
+//! - `serde`: Enables serialization of themes, style presets, and composition snapshots with [Serde](https://crates.io/crates/serde).
+//! - `tracing`: Enables logging and composition spans (one per composed node) through the `tracing` crate.
 //! - `ui`: Enables the `ui` module for user interface components.
 //! - `full`: Enables all features above.
 
 extern crate alloc;
 
 use ahash::AHasher;
-use alloc::rc::Rc;
+use alloc::{
+    collections::VecDeque,
+    rc::Rc,
+    sync::{Arc, Weak},
+    vec::Vec,
+};
 use core::{
     any::{Any, TypeId},
     cell::{Cell, RefCell, UnsafeCell},
@@ -135,8 +141,10 @@ use core::{
     ops::Deref,
     pin::Pin,
     ptr::NonNull,
+    sync::atomic::{AtomicU64, Ordering},
 };
-use slotmap::DefaultKey;
+use futures::future::AbortHandle;
+use slotmap::{DefaultKey, Key, SlotMap};
 use thiserror::Error;
 
 #[cfg(not(feature = "std"))]
@@ -148,21 +156,30 @@ use std::collections::HashMap;
 /// Prelude of commonly used items.
 pub mod prelude {
     pub use crate::{
-        compose::{self, catch, dyn_compose, memo, Compose, DynCompose, Error, Memo},
+        compose::{
+            self, catch, dyn_compose, error_boundary, keyed_memo, memo, memo_always_if, show,
+            Compose, DynCompose, Error, ErrorBoundary, Memo,
+        },
         data::{data, Data},
-        use_callback, use_context, use_drop, use_local_task, use_memo, use_mut, use_provider,
-        use_ref, Cow, Map, RefMap, Scope, ScopeState, Signal, SignalMut,
+        use_async, use_callback, use_context, use_drop, use_effect_with_cleanup, use_emitter,
+        use_event_bus, use_history, use_id, use_listener, use_local_task, use_memo, use_mut,
+        use_previous, use_provider, use_reducer, use_reducer_mw, use_ref, use_state, AsyncState,
+        Cow, Derived, Emitter, Id, Map, RefMap, Scope, ScopeState, SetState, Signal, SignalMut,
+        Subscription, UseHistory, UseReducer,
     };
 
     #[cfg(feature = "animation")]
     #[cfg_attr(docsrs, doc(cfg(feature = "animation")))]
-    pub use crate::animation::{use_animated, UseAnimated};
+    pub use crate::animation::{use_animated, use_animation_frame, UseAnimated};
 
     #[cfg(feature = "ecs")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ecs")))]
     pub use crate::ecs::{
-        spawn, use_bundle, use_commands, use_world, use_world_once, ActuatePlugin, Composition,
-        Modifier, Modify, Spawn, UseCommands,
+        spawn, use_bundle, use_commands, use_cursor, use_dropped_files, use_focus, use_interval,
+        use_keyboard_input, use_monitors, use_on_close, use_pointer_position, use_sequence,
+        use_window_handle, use_window_size, use_window_state, use_world, use_world_once,
+        ActuatePlugin, Composition, KeyCombo, Modifier, Modify, Spawn, UseCommands, UseFocus,
+        UseWindowState,
     };
 
     #[cfg(feature = "executor")]
@@ -171,14 +188,21 @@ pub mod prelude {
 
     #[cfg(feature = "ui")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ui")))]
-    pub use crate::ui::{scroll_view, ScrollView};
+    pub use crate::ui::{
+        animated_list, grid, image, image_rgba, opacity, rect, scroll_view, use_parent_size,
+        AnimatedList, Grid, ImageFit, ImageView, ListTransition, Opacity, RectView, ScrollView,
+    };
 
     #[cfg(feature = "material")]
     #[cfg_attr(docsrs, doc(cfg(feature = "material")))]
     pub use crate::ui::material::{
-        button, container, radio_button, text, Button, MaterialTheme, RadioButton, TypographyKind,
-        TypographyStyleKind,
+        button, container, radio_button, text, Button, MaterialTheme, RadioButton, ThemeError,
+        TypographyKind, TypographyStyleKind,
     };
+
+    #[cfg(all(feature = "material", feature = "serde"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "material", feature = "serde"))))]
+    pub use crate::ui::material::{use_style_preset, PresetError, StylePreset};
 }
 
 #[cfg(feature = "animation")]
@@ -192,7 +216,7 @@ use self::compose::{AnyCompose, Compose};
 
 /// Low-level composer.
 pub mod composer;
-use self::composer::Runtime;
+use self::composer::{Runtime, Subscriber};
 
 /// Data trait and derive macro.
 pub mod data;
@@ -401,6 +425,46 @@ impl<T> Hash for Map<'_, T> {
     }
 }
 
+/// Derived read-only value of type `U`, computed from a [`Signal`] of type `T`.
+///
+/// This can be created with [`Signal::derive`].
+pub struct Derived<'a, T, U> {
+    signal: Signal<'a, T>,
+    f: fn(&T) -> U,
+}
+
+impl<T, U> Derived<'_, T, U> {
+    /// Compute the current value of this derived value from its signal.
+    pub fn get(me: Self) -> U {
+        (me.f)(me.signal.value)
+    }
+}
+
+impl<T, U> Clone for Derived<'_, T, U> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, U> Copy for Derived<'_, T, U> {}
+
+impl<T, U: fmt::Debug> fmt::Debug for Derived<'_, T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Derived")
+            .field("value", &Derived::get(*self))
+            .field("generation", &unsafe { &*self.signal.generation }.get())
+            .finish()
+    }
+}
+
+impl<T, U> Hash for Derived<'_, T, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.signal.hash(state);
+    }
+}
+
+unsafe impl<T: Data, U> Data for Derived<'_, T, U> {}
+
 /// Immutable reference to a value of type `T`.
 ///
 /// Memoizing this value will use pointer-equality for higher-performance.
@@ -431,6 +495,91 @@ impl<'a, T> Signal<'a, T> {
         }
     }
 
+    /// Derive a read-only value of type `U` from this signal.
+    ///
+    /// The derived value is computed from the current value of this signal each time it's read with [`Derived::get`],
+    /// and is memoized by this signal's generation, so composables that depend on it
+    /// are re-composed when this signal changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Total<'a> {
+    ///     doubled: Derived<'a, i32, i32>,
+    /// }
+    ///
+    /// impl Compose for Total<'_> {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         dbg!(Derived::get(cx.me().doubled));
+    ///     }
+    /// }
+    ///
+    /// #[derive(Data)]
+    /// struct Counter;
+    ///
+    /// impl Compose for Counter {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let count = use_mut(&cx, || 0);
+    ///
+    ///         Total {
+    ///             doubled: Signal::derive(SignalMut::as_ref(count), |count| count * 2),
+    ///         }
+    ///     }
+    /// }
+    /// ```
+    pub fn derive<U>(me: Self, f: fn(&T) -> U) -> Derived<'a, T, U> {
+        Derived { signal: me, f }
+    }
+
+    /// Subscribe to updates of this signal's value, calling `f` with the new value after each update.
+    ///
+    /// `f` is called when an update from [`SignalMut::update`] (or [`SignalMut::set`]) is applied,
+    /// at the same point the owning composable is queued for re-composition.
+    /// This lets code outside of the composition (e.g. logging or IPC) observe the value.
+    ///
+    /// The subscription is removed when the returned [`Subscription`] is dropped,
+    /// which must happen before the composable that owns this value is dropped
+    /// (e.g. by storing it with [`use_ref`]).
+    ///
+    /// # Panics
+    /// Panics if called outside of a runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    ///
+    /// #[derive(Data)]
+    /// struct Counter;
+    ///
+    /// impl Compose for Counter {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let count = use_mut(&cx, || 0);
+    ///
+    ///         use_ref(&cx, || {
+    ///             Signal::subscribe(SignalMut::as_ref(count), |count| println!("Count: {count}"))
+    ///         });
+    ///     }
+    /// }
+    /// ```
+    pub fn subscribe(me: Self, f: impl Fn(&T) + 'static) -> Subscription
+    where
+        T: 'static,
+    {
+        let rt = Runtime::current();
+        let key = rt.subscribers.borrow_mut().insert(Subscriber {
+            ptr: me.value as *const T as *const (),
+            type_id: TypeId::of::<T>(),
+            // Safety: subscribers are only called with a pointer to a value of type `T`.
+            f: Rc::new(move |ptr| f(unsafe { &*(ptr as *const T) })),
+        });
+
+        Subscription { rt, key }
+    }
+
     /// Unsafely map this reference to a value of type `U`.
     /// The returned `MapUnchecked` implements `Compose` to allow for borrowed child composables.
     ///
@@ -495,7 +644,9 @@ impl<'a, T: 'static> SignalMut<'a, T> {
             let rt = Runtime::current();
             rt.queue(scope_key);
 
-            f(value)
+            f(value);
+
+            rt.notify(value as *const T as *const (), TypeId::of::<T>());
         })
     }
 
@@ -556,6 +707,57 @@ impl<T> Deref for SignalMut<'_, T> {
     }
 }
 
+/// Subscription to a [`Signal`], removed when dropped.
+///
+/// For more see [`Signal::subscribe`].
+#[must_use = "Subscriptions are removed when dropped."]
+pub struct Subscription {
+    rt: Runtime,
+    key: DefaultKey,
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        self.rt.subscribers.borrow_mut().remove(self.key);
+    }
+}
+
+/// Run `f`, batching every signal update it makes into a single update.
+///
+/// Each call to [`SignalMut::set`] or [`SignalMut::update`] normally queues its own update.
+/// Inside a batch, these updates are applied together (in the order they were made),
+/// so each affected composable is only re-composed once.
+/// Nested batches are part of their outer batch.
+///
+/// # Panics
+/// Panics if called outside of a runtime.
+///
+/// # Examples
+///
+/// ```
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Form;
+///
+/// impl Compose for Form {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let name = use_mut(&cx, String::new);
+///         let email = use_mut(&cx, String::new);
+///
+///         let _reset = move || {
+///             actuate::batch(|| {
+///                 SignalMut::set(name, String::new());
+///                 SignalMut::set(email, String::new());
+///             })
+///         };
+///     }
+/// }
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    Runtime::current().batch(f)
+}
+
 macro_rules! impl_pointer {
     ($($t:ident),*) => {
         $(
@@ -635,6 +837,9 @@ pub struct ScopeData<'a> {
     /// Drop functions to run just before this scope is dropped.
     drops: RefCell<Vec<usize>>,
 
+    /// Keys of local tasks spawned from this scope.
+    tasks: RefCell<Vec<DefaultKey>>,
+
     /// Current generation of this scope.
     generation: Cell<u64>,
 
@@ -644,6 +849,17 @@ pub struct ScopeData<'a> {
 
 impl Drop for ScopeData<'_> {
     fn drop(&mut self) {
+        let tasks = self.tasks.get_mut();
+        if !tasks.is_empty() {
+            // Scopes may be dropped outside of a runtime, where there are no tasks to remove.
+            if let Some(rt) = Runtime::try_current() {
+                let mut rt_tasks = rt.tasks.borrow_mut();
+                for key in tasks.drain(..) {
+                    rt_tasks.remove(key);
+                }
+            }
+        }
+
         for idx in &*self.drops.borrow() {
             let hooks = unsafe { &mut *self.hooks.get() };
             let any = hooks.get_mut(*idx).unwrap();
@@ -652,6 +868,58 @@ impl Drop for ScopeData<'_> {
     }
 }
 
+impl<'a> ScopeData<'a> {
+    /// Spawn a local task that runs on the current thread, tied to the lifetime of this scope.
+    ///
+    /// Unlike [`use_local_task`], this can be called any number of times (e.g. on every composition).
+    /// The task is polled until it completes, and is cancelled if this scope is dropped first.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[derive(Data)]
+    /// struct Search {
+    ///     query: String,
+    /// }
+    ///
+    /// impl Compose for Search {
+    ///     fn compose(cx: Scope<Self>) -> impl Compose {
+    ///         let results = use_mut(&cx, Vec::new);
+    ///
+    ///         let query = cx.me().query.clone();
+    ///         use_memo(&cx, query.clone(), || {
+    ///             cx.spawn(async move {
+    ///                 let found = vec![format!("{query} (1)"), format!("{query} (2)")];
+    ///                 SignalMut::set(results, found);
+    ///             })
+    ///         });
+    ///
+    ///         spawn(Text::new(results.join("\n")))
+    ///     }
+    /// }
+    /// ```
+    pub fn spawn(&self, task: impl Future<Output = ()> + 'a) {
+        let task: Pin<Box<dyn Future<Output = ()> + 'a>> = Box::pin(task);
+
+        // Safety: `task` is removed from the runtime before this scope is dropped.
+        let task: Pin<Box<dyn Future<Output = ()>>> = unsafe { mem::transmute(task) };
+
+        let rt = Runtime::current();
+        let mut rt_tasks = rt.tasks.borrow_mut();
+        let key = rt_tasks.insert(task);
+        rt.task_queue.push(key);
+
+        // Forget tasks that already completed, so long-lived scopes don't keep every key.
+        let mut tasks = self.tasks.borrow_mut();
+        tasks.retain(|key| rt_tasks.contains_key(*key));
+        tasks.push(key);
+    }
+}
+
 /// Composable scope.
 pub struct Scope<'a, C: ?Sized> {
     me: &'a C,
@@ -741,6 +1009,149 @@ pub fn use_mut<T: 'static>(cx: ScopeState, make_value: impl FnOnce() -> T) -> Si
     }
 }
 
+struct StateCell<T> {
+    value: std::sync::Mutex<T>,
+    scope_key: DefaultKey,
+}
+
+/// Setter for a [`use_state`] hook.
+///
+/// This can be cloned and moved into callbacks without borrowing the scope.
+/// Setting a value after its composable has been removed does nothing.
+pub struct SetState<T> {
+    cell: Weak<StateCell<T>>,
+}
+
+impl<T: Send + 'static> SetState<T> {
+    /// Queue an update to this value, triggering an update to the component owning this value.
+    pub fn set(&self, value: T) {
+        self.update(move |x| *x = value)
+    }
+
+    /// Queue an update to this value with a function,
+    /// triggering an update to the component owning this value.
+    pub fn update(&self, f: impl FnOnce(&mut T) + Send + 'static) {
+        let cell = self.cell.clone();
+
+        Runtime::current().update(move || {
+            let Some(state) = cell.upgrade() else {
+                return;
+            };
+
+            f(&mut state.value.lock().unwrap());
+            Runtime::current().queue(state.scope_key);
+        });
+    }
+}
+
+impl<T> Clone for SetState<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: self.cell.clone(),
+        }
+    }
+}
+
+impl<T> fmt::Debug for SetState<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("SetState").finish_non_exhaustive()
+    }
+}
+
+unsafe impl<T: Data> Data for SetState<T> {}
+
+/// Use a value of type `T` and a setter for it.
+///
+/// `make_value` will only be called once to initialize this value.
+///
+/// Unlike [`use_mut`], the returned [`SetState`] is `'static` (and `Send` when `T` is),
+/// so it can be cloned and moved into callbacks without borrowing the scope.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Counter;
+///
+/// impl Compose for Counter {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let (count, set_count) = use_state(&cx, || 0);
+///
+///         (
+///             text::headline(format!("High five count: {}", count)),
+///             button(text::label("Up high")).on_click(move || set_count.update(|x| *x += 1)),
+///         )
+///     }
+/// }
+/// ```
+pub fn use_state<T: Clone + 'static>(
+    cx: ScopeState,
+    make_value: impl FnOnce() -> T,
+) -> (T, SetState<T>) {
+    let cell = use_ref(cx, || {
+        Arc::new(StateCell {
+            value: std::sync::Mutex::new(make_value()),
+            scope_key: Runtime::current().current_key.get(),
+        })
+    });
+
+    let value = cell.value.lock().unwrap().clone();
+    (
+        value,
+        SetState {
+            cell: Arc::downgrade(cell),
+        },
+    )
+}
+
+/// Unique identifier of a [`use_id`] hook within a composition.
+///
+/// This is formatted as a string like `actuate-4294967297-0`, for use in accessibility relationships or list keys.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Id {
+    key: u64,
+    idx: usize,
+}
+
+impl fmt::Display for Id {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "actuate-{}-{}", self.key, self.idx)
+    }
+}
+
+/// Use a unique identifier that is stable across re-compositions.
+///
+/// The identifier is derived from this composable's node in the composition and the position of this hook,
+/// so it is generated once and never reused by another hook in the same composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Field;
+///
+/// impl Compose for Field {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let id = use_id(&cx);
+///
+///         text::label(format!("Label for {id}"))
+///     }
+/// }
+/// ```
+pub fn use_id(cx: ScopeState) -> Id {
+    let idx = cx.hook_idx.get();
+
+    *use_ref(cx, || Id {
+        key: Runtime::current().current_key.get().data().as_ffi(),
+        idx,
+    })
+}
+
 /// Use a callback function.
 /// The returned function will be updated to `f` whenever this component is re-composed.
 pub fn use_callback<'a, T, R>(
@@ -830,6 +1241,193 @@ pub fn use_provider<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() ->
     })
 }
 
+type ListenerFn<E> = Rc<dyn Fn(Rc<E>)>;
+
+/// Bus and key of a listener registered with [`use_listener`].
+type ListenerRegistration<E> = RefCell<Option<(Rc<EventBus<E>>, DefaultKey)>>;
+
+/// Event bus for events of type `E`.
+///
+/// For more see [`use_event_bus`].
+pub struct EventBus<E> {
+    listeners: RefCell<SlotMap<DefaultKey, ListenerFn<E>>>,
+}
+
+impl<E> Default for EventBus<E> {
+    fn default() -> Self {
+        Self {
+            listeners: RefCell::new(SlotMap::new()),
+        }
+    }
+}
+
+/// Emitter of events of type `E`.
+///
+/// For more see [`use_emitter`].
+pub struct Emitter<'a, E> {
+    bus: *const EventBus<E>,
+    _marker: PhantomData<&'a EventBus<E>>,
+}
+
+impl<E: Send + 'static> Emitter<'_, E> {
+    /// Queue an event to be sent to every listener of this emitter's [`EventBus`].
+    pub fn emit(self, event: E) {
+        let bus = UnsafeWrap(self.bus);
+
+        Runtime::current().update(move || {
+            let bus = bus;
+
+            // Safety: updates are applied on the thread that owns the composition,
+            // before any structural changes, so the bus is still alive.
+            let bus = unsafe { &*bus.0 };
+
+            let event = Rc::new(event);
+            let listeners: Vec<_> = bus.listeners.borrow().values().cloned().collect();
+            for listener in listeners {
+                listener(event.clone());
+            }
+        });
+    }
+}
+
+impl<E> Clone for Emitter<'_, E> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<E> Copy for Emitter<'_, E> {}
+
+// Safety: the bus is never cloned or dropped through an emitter,
+// and it's only dereferenced by updates on the thread that owns the composition.
+unsafe impl<E: Send> Send for Emitter<'_, E> {}
+
+unsafe impl<E: Send> Sync for Emitter<'_, E> {}
+
+unsafe impl<E: Send + 'static> Data for Emitter<'_, E> {}
+
+/// Provide an [`EventBus`] for events of type `E`.
+///
+/// Children of this composable can send events with [`use_emitter`], and receive events with [`use_listener`].
+/// Events are delivered in the update queue, so listeners run (and trigger re-compositions) consistently with other updates.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// struct ShowToast(String);
+///
+/// #[derive(Data)]
+/// struct Toast;
+///
+/// impl Compose for Toast {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let message = use_mut(&cx, || None);
+///
+///         use_listener(&cx, move |event: &ShowToast| {
+///             SignalMut::set(message, Some(event.0.clone()))
+///         })
+///         .unwrap();
+///
+///         (*message).clone().map(text::label)
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct SaveButton;
+///
+/// impl Compose for SaveButton {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let emitter = use_emitter::<ShowToast>(&cx).unwrap();
+///
+///         button(text::label("Save"))
+///             .on_click(move || emitter.emit(ShowToast(String::from("Saved!"))))
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         use_event_bus::<ShowToast>(&cx);
+///
+///         (Toast, SaveButton)
+///     }
+/// }
+/// ```
+pub fn use_event_bus<E: 'static>(cx: ScopeState<'_>) -> &Rc<EventBus<E>> {
+    use_provider(cx, EventBus::default)
+}
+
+/// Use an [`Emitter`] for events of type `E`.
+///
+/// The [`EventBus`] must have already been provided by a parent composable with [`use_event_bus`],
+/// otherwise this function will return a [`ContextError`].
+pub fn use_emitter<E: Send + 'static>(
+    cx: ScopeState<'_>,
+) -> Result<Emitter<'_, E>, ContextError<EventBus<E>>> {
+    use_context::<EventBus<E>>(cx).map(|bus| Emitter {
+        bus: Rc::as_ptr(bus),
+        _marker: PhantomData,
+    })
+}
+
+/// Use a listener for events of type `E`.
+///
+/// `f` is updated whenever this composable is re-composed, and is removed from the [`EventBus`] when this composable is dropped.
+///
+/// The [`EventBus`] is looked up on every composition, so `f` is subscribed to a bus that's provided after the first composition
+/// (and moved to a new bus if the provided bus changes).
+/// If no bus has been provided by a parent composable with [`use_event_bus`], this function will return a [`ContextError`].
+pub fn use_listener<'a, E: 'static>(
+    cx: ScopeState<'a>,
+    mut f: impl FnMut(&E) + 'a,
+) -> Result<(), ContextError<EventBus<E>>> {
+    let bus = cx
+        .contexts
+        .borrow()
+        .values
+        .get(&TypeId::of::<EventBus<E>>())
+        .cloned()
+        .map(|any| Rc::downcast::<EventBus<E>>(any).unwrap());
+
+    let callback = use_callback(cx, move |event: Rc<E>| f(&event)).clone();
+
+    let registration: &ListenerRegistration<E> = use_ref(cx, || RefCell::new(None));
+
+    let is_registered = match (&*registration.borrow(), &bus) {
+        (Some((last_bus, _)), Some(bus)) => Rc::ptr_eq(last_bus, bus),
+        (None, None) => true,
+        _ => false,
+    };
+
+    if !is_registered {
+        let mut registration = registration.borrow_mut();
+        if let Some((last_bus, key)) = registration.take() {
+            last_bus.listeners.borrow_mut().remove(key);
+        }
+
+        if let Some(bus) = &bus {
+            // Safety: `callback` is removed from the bus when this scope is dropped.
+            let callback: ListenerFn<E> = unsafe { mem::transmute(callback) };
+            let key = bus.listeners.borrow_mut().insert(callback);
+            *registration = Some((bus.clone(), key));
+        }
+    }
+
+    use_drop(cx, move || {
+        if let Some((bus, key)) = registration.borrow_mut().take() {
+            bus.listeners.borrow_mut().remove(key);
+        }
+    });
+
+    bus.map(|_| ()).ok_or(ContextError {
+        _marker: PhantomData,
+    })
+}
+
 /// Memoize a value, caching it until the dependency changes.
 /// This can be used to diff expensive values by pointer equality.
 ///
@@ -838,6 +1436,7 @@ pub fn use_provider<T: 'static>(cx: ScopeState<'_>, make_value: impl FnOnce() ->
 /// - [`Signal`]
 /// - [`SignalMut`]
 /// - [`Map`]
+/// - [`Derived`]
 /// - [`RefMap`]
 /// - [`Cow`]
 pub trait Memoize {
@@ -872,6 +1471,14 @@ impl<T> Memoize for Map<'_, T> {
     }
 }
 
+impl<T, U> Memoize for Derived<'_, T, U> {
+    type Value = u64;
+
+    fn memoized(self) -> Self::Value {
+        self.signal.memoized()
+    }
+}
+
 impl<T: Clone + PartialEq + 'static> Memoize for RefMap<'_, T> {
     type Value = MemoizedCow<T>;
 
@@ -937,9 +1544,85 @@ where
     }
 }
 
+/// Use an effect that will run whenever the provided dependency is changed,
+/// returning a cleanup function.
+///
+/// The cleanup function returned by `effect` is called before the effect runs again,
+/// and when this scope is dropped.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Subscription {
+///     topic: String,
+/// }
+///
+/// impl Compose for Subscription {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         use_effect_with_cleanup(&cx, cx.me().topic.clone(), |topic| {
+///             info!("Subscribed to {topic}");
+///
+///             let topic = topic.clone();
+///             move || info!("Unsubscribed from {topic}")
+///         });
+///     }
+/// }
+/// ```
+pub fn use_effect_with_cleanup<D, F>(cx: ScopeState, dependency: D, effect: impl FnOnce(&D) -> F)
+where
+    D: PartialEq + 'static,
+    F: FnOnce() + 'static,
+{
+    let cleanup = use_ref(cx, || RefCell::new(None::<Box<dyn FnOnce()>>));
+    let last = use_ref(cx, || RefCell::new(None::<D>));
+
+    if last.borrow().as_ref() != Some(&dependency) {
+        if let Some(f) = cleanup.borrow_mut().take() {
+            f();
+        }
+
+        *cleanup.borrow_mut() = Some(Box::new(effect(&dependency)));
+        *last.borrow_mut() = Some(dependency);
+    }
+
+    use_drop(cx, move || {
+        if let Some(f) = cleanup.borrow_mut().take() {
+            f();
+        }
+    });
+}
+
 /// Use a memoized value of type `T` with a dependency of type `D`.
 ///
 /// `make_value` will update the returned value whenver `dependency` is changed.
+/// The new value is computed immediately, so it is returned during the same composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Total {
+///     prices: Vec<u32>,
+/// }
+///
+/// impl Compose for Total {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Only re-calculate the total when the prices change.
+///         let total = use_memo(&cx, cx.me().prices.clone(), || {
+///             cx.me().prices.iter().sum::<u32>()
+///         });
+///
+///         spawn(Text::new(format!("Total: {}", *total)))
+///     }
+/// }
+/// ```
 pub fn use_memo<D, T>(cx: ScopeState, dependency: D, make_value: impl FnOnce() -> T) -> Signal<T>
 where
     D: PartialEq + Send + 'static,
@@ -949,22 +1632,431 @@ where
     let mut make_value_cell = Some(make_value);
 
     let value_mut = use_mut(cx, || make_value_cell.take().unwrap()());
-    let last_mut = use_mut(cx, || dependency_cell.take().unwrap());
+    let last = use_ref(cx, || RefCell::new(dependency_cell.take().unwrap()));
 
-    if let Some(make_value) = make_value_cell {
-        if let Some(dependency) = dependency_cell.take() {
-            if dependency != *last_mut {
-                let value = make_value();
-                SignalMut::with(value_mut, move |update| *update = value);
+    if let (Some(make_value), Some(dependency)) = (make_value_cell, dependency_cell) {
+        if dependency != *last.borrow() {
+            // Safety: references to this value can only be created after this hook returns,
+            // so there are no other references to it while composing this scope.
+            unsafe { *value_mut.ptr.as_ptr() = make_value() };
 
-                SignalMut::with(last_mut, move |dst| *dst = dependency);
-            }
+            let generation = unsafe { &*value_mut.generation };
+            generation.set(generation.get() + 1);
+
+            *last.borrow_mut() = dependency;
         }
     }
 
     SignalMut::as_ref(value_mut)
 }
 
+/// Use the value of `current` from the last composition, storing `current` for the next one.
+///
+/// This returns `None` on the first composition.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Score {
+///     value: u32,
+/// }
+///
+/// impl Compose for Score {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let last_value = use_previous(&cx, cx.me().value);
+///
+///         // Highlight the score when it increases.
+///         let color = if last_value.is_some_and(|last| last < cx.me().value) {
+///             Color::srgb(0., 1., 0.)
+///         } else {
+///             Color::WHITE
+///         };
+///
+///         spawn((Text::new(cx.me().value.to_string()), TextColor(color)))
+///     }
+/// }
+/// ```
+pub fn use_previous<T: 'static>(cx: ScopeState, current: T) -> Option<T> {
+    let last = use_ref(cx, || RefCell::new(None));
+    last.replace(Some(current))
+}
+
+type ReduceFn<S, A> = Arc<dyn Fn(&mut S, A) + Send + Sync>;
+
+/// Reducer of type `S` with actions of type `A`.
+///
+/// For more see [`use_reducer`].
+pub struct UseReducer<'a, S, A> {
+    state: SignalMut<'a, S>,
+    reduce: &'a std::sync::Mutex<ReduceFn<S, A>>,
+}
+
+impl<'a, S: 'static, A: Send + 'static> UseReducer<'a, S, A> {
+    /// Queue an action to be applied to this reducer's state,
+    /// triggering an update to the component owning this reducer.
+    pub fn dispatch(me: Self, action: A) {
+        let reduce = me.reduce.lock().unwrap().clone();
+        SignalMut::update(me.state, move |state| reduce(state, action));
+    }
+
+    /// Convert this reducer to an immutable reference to its state.
+    pub fn as_ref(me: Self) -> Signal<'a, S> {
+        SignalMut::as_ref(me.state)
+    }
+}
+
+impl<S, A> Clone for UseReducer<'_, S, A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S, A> Copy for UseReducer<'_, S, A> {}
+
+impl<S, A> Deref for UseReducer<'_, S, A> {
+    type Target = S;
+
+    fn deref(&self) -> &Self::Target {
+        &self.state
+    }
+}
+
+unsafe impl<S: Data, A> Data for UseReducer<'_, S, A> {}
+
+/// Use a reducer of state `S` with actions of type `A`.
+///
+/// Actions sent with [`UseReducer::dispatch`] are applied by `reducer` in the update queue,
+/// replacing the current state with the returned state.
+/// To observe each action and state change, see [`use_reducer_mw`].
+///
+/// `make_initial` will only be called once to initialize the state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// enum Action {
+///     Add(String),
+///     Clear,
+/// }
+///
+/// #[derive(Data)]
+/// struct Log;
+///
+/// impl Compose for Log {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let lines = use_reducer(&cx, Vec::new, |lines: &Vec<String>, action: &Action| {
+///             match action {
+///                 Action::Add(line) => {
+///                     let mut lines = lines.clone();
+///                     lines.push(line.clone());
+///                     lines
+///                 }
+///                 Action::Clear => Vec::new(),
+///             }
+///         });
+///
+///         (
+///             text::body(lines.join("\n")),
+///             button(text::label("Ping"))
+///                 .on_click(move || UseReducer::dispatch(lines, Action::Add(String::from("Ping")))),
+///             button(text::label("Clear")).on_click(move || UseReducer::dispatch(lines, Action::Clear)),
+///         )
+///     }
+/// }
+/// ```
+pub fn use_reducer<'a, S, A>(
+    cx: ScopeState<'a>,
+    make_initial: impl FnOnce() -> S,
+    reducer: impl Fn(&S, &A) -> S + Send + Sync + 'static,
+) -> UseReducer<'a, S, A>
+where
+    S: 'static,
+    A: 'static,
+{
+    use_reducer_mw(cx, make_initial, reducer, |_, _, _| {})
+}
+
+/// Use a reducer of state `S` with actions of type `A`, observed by `middleware`.
+///
+/// Actions sent with [`UseReducer::dispatch`] are applied by `reducer` in the update queue,
+/// replacing the current state with the returned state.
+/// `middleware` then runs synchronously in the same update with the action,
+/// the previous state, and the new state (e.g. for logging, undo stacks, or time-travel debugging).
+///
+/// `make_initial` will only be called once to initialize the state.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Clone, Debug)]
+/// enum Action {
+///     Increment,
+///     Reset,
+/// }
+///
+/// #[derive(Data)]
+/// struct Counter;
+///
+/// impl Compose for Counter {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let count = use_reducer_mw(
+///             &cx,
+///             || 0,
+///             |count: &i32, action: &Action| match action {
+///                 Action::Increment => count + 1,
+///                 Action::Reset => 0,
+///             },
+///             |action, before, after| println!("{action:?}: {before} -> {after}"),
+///         );
+///
+///         (
+///             text::headline(format!("High five count: {}", *count)),
+///             button(text::label("Up high"))
+///                 .on_click(move || UseReducer::dispatch(count, Action::Increment)),
+///             button(text::label("Reset")).on_click(move || UseReducer::dispatch(count, Action::Reset)),
+///         )
+///     }
+/// }
+/// ```
+pub fn use_reducer_mw<'a, S, A>(
+    cx: ScopeState<'a>,
+    make_initial: impl FnOnce() -> S,
+    reducer: impl Fn(&S, &A) -> S + Send + Sync + 'static,
+    middleware: impl Fn(&A, &S, &S) + Send + Sync + 'static,
+) -> UseReducer<'a, S, A>
+where
+    S: 'static,
+    A: 'static,
+{
+    let state = use_mut(cx, make_initial);
+
+    let reduce: ReduceFn<S, A> = Arc::new(move |state: &mut S, action: A| {
+        let mut new_state = reducer(state, &action);
+        mem::swap(state, &mut new_state);
+        middleware(&action, &new_state, state);
+    });
+
+    let mut reduce_cell = Some(reduce);
+    let reduce_ref = use_ref(cx, || std::sync::Mutex::new(reduce_cell.take().unwrap()));
+
+    // Update the reducer in case it captures new values.
+    // This is behind a `Mutex` as actions may be dispatched from other threads.
+    if let Some(reduce) = reduce_cell {
+        *reduce_ref.lock().unwrap() = reduce;
+    }
+
+    UseReducer {
+        state,
+        reduce: reduce_ref,
+    }
+}
+
+/// Bounded undo and redo history of values of type `T`.
+///
+/// For more see [`use_history`].
+#[derive(Clone, Debug)]
+pub struct History<T> {
+    past: VecDeque<T>,
+    present: T,
+    future: Vec<T>,
+    capacity: usize,
+}
+
+impl<T> History<T> {
+    /// Default maximum number of undo states.
+    pub const DEFAULT_CAPACITY: usize = 100;
+
+    /// Create a new history starting at `value`, keeping at most `capacity` undo states.
+    pub fn new(value: T, capacity: usize) -> Self {
+        Self {
+            past: VecDeque::new(),
+            present: value,
+            future: Vec::new(),
+            capacity,
+        }
+    }
+
+    /// Get the current value of this history.
+    pub fn get(&self) -> &T {
+        &self.present
+    }
+
+    /// Push a new value to this history, clearing any redo states.
+    ///
+    /// If this history is full, the oldest undo state is discarded.
+    pub fn push(&mut self, value: T) {
+        let last = mem::replace(&mut self.present, value);
+        self.past.push_back(last);
+
+        if self.past.len() > self.capacity {
+            self.past.pop_front();
+        }
+
+        self.future.clear();
+    }
+
+    /// Move back to the previous value, returning `true` if there was a value to undo.
+    pub fn undo(&mut self) -> bool {
+        if let Some(value) = self.past.pop_back() {
+            let next = mem::replace(&mut self.present, value);
+            self.future.push(next);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Move forward to the next value, returning `true` if there was a value to redo.
+    pub fn redo(&mut self) -> bool {
+        if let Some(value) = self.future.pop() {
+            let last = mem::replace(&mut self.present, value);
+            self.past.push_back(last);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` if there is a value to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    /// Returns `true` if there is a value to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+}
+
+/// Undo and redo history of values of type `T`.
+///
+/// For more see [`use_history`].
+pub struct UseHistory<'a, T> {
+    history: SignalMut<'a, History<T>>,
+}
+
+impl<'a, T: Send + 'static> UseHistory<'a, T> {
+    /// Queue a new value to be pushed to this history.
+    pub fn push(me: Self, value: T) {
+        SignalMut::update(me.history, move |history| history.push(value));
+    }
+
+    /// Queue a move back to the previous value.
+    ///
+    /// This will only trigger an update if there is a value to undo.
+    pub fn undo(me: Self) {
+        if me.history.can_undo() {
+            SignalMut::update(me.history, |history| {
+                history.undo();
+            });
+        }
+    }
+
+    /// Queue a move forward to the next value.
+    ///
+    /// This will only trigger an update if there is a value to redo.
+    pub fn redo(me: Self) {
+        if me.history.can_redo() {
+            SignalMut::update(me.history, |history| {
+                history.redo();
+            });
+        }
+    }
+
+    /// Returns `true` if there is a value to undo.
+    pub fn can_undo(me: Self) -> bool {
+        me.history.can_undo()
+    }
+
+    /// Returns `true` if there is a value to redo.
+    pub fn can_redo(me: Self) -> bool {
+        me.history.can_redo()
+    }
+
+    /// Convert this history to an immutable reference to its [`History`].
+    pub fn as_ref(me: Self) -> Signal<'a, History<T>> {
+        SignalMut::as_ref(me.history)
+    }
+}
+
+impl<T> Clone for UseHistory<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for UseHistory<'_, T> {}
+
+impl<T> Deref for UseHistory<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.history.get()
+    }
+}
+
+unsafe impl<T: Send + Sync> Send for UseHistory<'_, T> {}
+
+unsafe impl<T: Send + Sync> Sync for UseHistory<'_, T> {}
+
+unsafe impl<T: Data> Data for UseHistory<'_, T> {}
+
+/// Use an undo and redo history of values of type `T`.
+///
+/// The history keeps at most [`History::DEFAULT_CAPACITY`] undo states.
+/// For a different bound see [`use_history_with_capacity`].
+///
+/// `make_initial` will only be called once to initialize this history.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Editor;
+///
+/// impl Compose for Editor {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let count = use_history(&cx, || 0);
+///
+///         (
+///             text::headline(format!("High five count: {}", *count)),
+///             button(text::label("Up high")).on_click(move || UseHistory::push(count, *count + 1)),
+///             button(text::label("Undo")).on_click(move || UseHistory::undo(count)),
+///             button(text::label("Redo")).on_click(move || UseHistory::redo(count)),
+///         )
+///     }
+/// }
+/// ```
+pub fn use_history<T: 'static>(
+    cx: ScopeState<'_>,
+    make_initial: impl FnOnce() -> T,
+) -> UseHistory<'_, T> {
+    use_history_with_capacity(cx, History::<T>::DEFAULT_CAPACITY, make_initial)
+}
+
+/// Use an undo and redo history of values of type `T`, keeping at most `capacity` undo states.
+///
+/// For more see [`use_history`].
+pub fn use_history_with_capacity<T: 'static>(
+    cx: ScopeState<'_>,
+    capacity: usize,
+    make_initial: impl FnOnce() -> T,
+) -> UseHistory<'_, T> {
+    let history = use_mut(cx, || History::new(make_initial(), capacity));
+
+    UseHistory { history }
+}
+
 /// Use a function that will be called when this scope is dropped.
 pub fn use_drop<'a>(cx: ScopeState<'a>, f: impl FnOnce() + 'a) {
     let mut f_cell = Some(f);
@@ -1023,6 +2115,134 @@ where
     })
 }
 
+/// State of an asynchronous value from [`use_async`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum AsyncState<T, E> {
+    /// The future is still running.
+    Loading,
+
+    /// The future completed with a value.
+    Ready(T),
+
+    /// The future completed with an error.
+    Failed(E),
+}
+
+impl<T, E> AsyncState<T, E> {
+    /// Returns `true` if the future is still running.
+    pub fn is_loading(&self) -> bool {
+        matches!(self, AsyncState::Loading)
+    }
+
+    /// Get the value of the completed future, if it succeeded.
+    pub fn ready(&self) -> Option<&T> {
+        match self {
+            AsyncState::Ready(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Get the error of the completed future, if it failed.
+    pub fn failed(&self) -> Option<&E> {
+        match self {
+            AsyncState::Failed(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Use an asynchronous value from a future that runs on the current thread.
+///
+/// The future is created with `make_future` on the first composition, and again whenever `dependency` changes.
+/// When the dependency changes, the previous future is cancelled and an update resetting the state
+/// to [`AsyncState::Loading`] is queued (so a result from an outdated future is never returned).
+/// The current composable is re-composed when the future completes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// async fn fetch_name(user_id: u64) -> Result<String, String> {
+///     Ok(format!("User {user_id}"))
+/// }
+///
+/// #[derive(Data)]
+/// struct UserName {
+///     user_id: u64,
+/// }
+///
+/// impl Compose for UserName {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let user_id = cx.me().user_id;
+///         let name = use_async(&cx, user_id, move || fetch_name(user_id));
+///
+///         let label = match &*name {
+///             AsyncState::Loading => String::from("Loading..."),
+///             AsyncState::Ready(name) => name.clone(),
+///             AsyncState::Failed(error) => format!("Error: {error}"),
+///         };
+///
+///         spawn(Text::new(label))
+///     }
+/// }
+/// ```
+pub fn use_async<'a, D, F, T, E>(
+    cx: ScopeState<'a>,
+    dependency: D,
+    make_future: impl FnOnce() -> F,
+) -> Signal<'a, AsyncState<T, E>>
+where
+    D: PartialEq + 'static,
+    F: Future<Output = Result<T, E>> + 'a,
+    T: Send + 'static,
+    E: Send + 'static,
+{
+    let state = use_mut(cx, || AsyncState::Loading);
+    let abort_handle: &RefCell<Option<AbortHandle>> = use_ref(cx, || RefCell::new(None));
+    let last: &RefCell<Option<D>> = use_ref(cx, || RefCell::new(None));
+
+    // Generation of the current future, used to ignore results of outdated futures.
+    let task_generation = use_ref(cx, || Arc::new(AtomicU64::new(0)));
+
+    if last.borrow().as_ref() != Some(&dependency) {
+        *last.borrow_mut() = Some(dependency);
+
+        let generation = task_generation.fetch_add(1, Ordering::SeqCst) + 1;
+
+        // Cancel the previous future.
+        // Resetting the state is queued after any result of that future that's already queued,
+        // so an outdated result can't be applied after the reset.
+        if let Some(handle) = abort_handle.take() {
+            handle.abort();
+            SignalMut::set(state, AsyncState::Loading);
+        }
+
+        let (future, handle) = futures::future::abortable(make_future());
+        *abort_handle.borrow_mut() = Some(handle);
+
+        let task_generation = task_generation.clone();
+        cx.spawn(async move {
+            let Ok(result) = future.await else {
+                return;
+            };
+
+            if task_generation.load(Ordering::SeqCst) == generation {
+                SignalMut::set(
+                    state,
+                    match result {
+                        Ok(value) => AsyncState::Ready(value),
+                        Err(error) => AsyncState::Failed(error),
+                    },
+                );
+            }
+        });
+    }
+
+    SignalMut::as_ref(state)
+}
+
 #[cfg(feature = "executor")]
 type BoxedFuture = Pin<Box<dyn Future<Output = ()> + Send>>;
 
@@ -1049,7 +2269,8 @@ impl Future for TaskFuture {
         if let Some(task) = &mut *guard {
             me.rt.enter();
 
-            let _guard = Box::pin(me.rt.lock.read()).as_mut().poll(cx);
+            // Wait for any running update to finish, without blocking this executor's thread.
+            let _guard = futures::ready!(me.rt.lock.poll_read(cx));
 
             task.as_mut().poll(cx)
         } else {
@@ -0,0 +1,215 @@
+use crate::{
+    compose::Compose,
+    data::Data,
+    ecs::{spawn, use_world, use_world_once, Modifier, Modify},
+    use_mut, Scope, SignalMut,
+};
+use bevy_asset::{Assets, Handle, RenderAssetUsages};
+use bevy_ecs::prelude::*;
+use bevy_image::Image;
+use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_ui::{prelude::*, widget::NodeImageMode, ComputedNode};
+use std::mem;
+
+/// Fit of an image inside its node.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ImageFit {
+    /// Scale the image to fit inside the node, keeping its aspect ratio.
+    #[default]
+    Contain,
+
+    /// Scale the image to cover the node, keeping its aspect ratio and clipping any overflow.
+    Cover,
+
+    /// Stretch the image to the size of the node, ignoring its aspect ratio.
+    Stretch,
+}
+
+enum ImageSource {
+    Handle(Handle<Image>),
+    Rgba {
+        width: u32,
+        height: u32,
+        pixels: Vec<u8>,
+    },
+}
+
+/// Create an image composable from an image asset.
+///
+/// Images can be loaded from files with the [`AssetServer`](bevy_asset::AssetServer),
+/// which decodes formats like PNG and JPEG (depending on Bevy's enabled features).
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Avatar;
+///
+/// impl Compose for Avatar {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let handle = use_world_once(&cx, |asset_server: Res<AssetServer>| {
+///             asset_server.load("avatar.png")
+///         });
+///
+///         image(handle.clone())
+///             .fit(ImageFit::Cover)
+///             .width(Val::Px(64.))
+///             .height(Val::Px(64.))
+///     }
+/// }
+/// ```
+pub fn image<'a>(handle: Handle<Image>) -> ImageView<'a> {
+    ImageView {
+        source: ImageSource::Handle(handle),
+        fit: ImageFit::default(),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Create an image composable from RGBA pixels, with 8 bits per channel in the sRGB color space.
+///
+/// The image is uploaded once on the first composition, later changes to `pixels` are ignored.
+/// To update an image's pixels, modify its asset in [`Assets<Image>`] and display it with [`image`].
+///
+/// # Panics
+///
+/// Panics if the length of `pixels` is not `width * height * 4`.
+pub fn image_rgba<'a>(width: u32, height: u32, pixels: Vec<u8>) -> ImageView<'a> {
+    assert_eq!(
+        pixels.len(),
+        width as usize * height as usize * 4,
+        "Expected {width}x{height} RGBA pixels."
+    );
+
+    ImageView {
+        source: ImageSource::Rgba {
+            width,
+            height,
+            pixels,
+        },
+        fit: ImageFit::default(),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Image composable.
+///
+/// For more see [`image`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct ImageView<'a> {
+    source: ImageSource,
+    fit: ImageFit,
+    modifier: Modifier<'a>,
+}
+
+impl ImageView<'_> {
+    /// Set the fit of this image inside its node (default: [`ImageFit::Contain`]).
+    pub fn fit(mut self, fit: ImageFit) -> Self {
+        self.fit = fit;
+        self
+    }
+}
+
+unsafe impl Data for ImageView<'_> {}
+
+impl Compose for ImageView<'_> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let handle = use_world_once(&cx, |mut images: ResMut<Assets<Image>>| {
+            match &cx.me().source {
+                ImageSource::Handle(handle) => handle.clone(),
+                ImageSource::Rgba {
+                    width,
+                    height,
+                    pixels,
+                } => images.add(Image::new(
+                    Extent3d {
+                        width: *width,
+                        height: *height,
+                        depth_or_array_layers: 1,
+                    },
+                    TextureDimension::D2,
+                    pixels.clone(),
+                    TextureFormat::Rgba8UnormSrgb,
+                    RenderAssetUsages::default(),
+                )),
+            }
+        });
+
+        let entity_cell = use_mut(&cx, || None);
+
+        // Size of the image inside its node, in logical pixels.
+        let size = use_mut(&cx, || None);
+        use_world(
+            &cx,
+            move |images: Res<Assets<Image>>, node_query: Query<&ComputedNode>| {
+                let Some(node) = (*entity_cell).and_then(|entity| node_query.get(entity).ok())
+                else {
+                    return;
+                };
+                let Some(image) = images.get(handle) else {
+                    return;
+                };
+
+                let node_size = node.size() * node.inverse_scale_factor();
+                let image_size = image.size().as_vec2();
+                if image_size.x <= 0. || image_size.y <= 0. {
+                    return;
+                }
+
+                let scale_x = node_size.x / image_size.x;
+                let scale_y = node_size.y / image_size.y;
+                let scale = match cx.me().fit {
+                    ImageFit::Contain => scale_x.min(scale_y),
+                    ImageFit::Cover => scale_x.max(scale_y),
+                    ImageFit::Stretch => return,
+                };
+
+                SignalMut::set_if_neq(size, Some((image_size.x * scale, image_size.y * scale)));
+            },
+        );
+
+        let (width, height) = match (cx.me().fit, *size) {
+            (ImageFit::Stretch, _) => (Val::Percent(100.), Val::Percent(100.)),
+            (_, Some((width, height))) => (Val::Px(width), Val::Px(height)),
+            (_, None) => (Val::Px(0.), Val::Px(0.)),
+        };
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(
+                spawn(Node {
+                    width: Val::Percent(100.),
+                    height: Val::Percent(100.),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    overflow: Overflow::clip(),
+                    ..Default::default()
+                })
+                .on_insert(move |entity| SignalMut::set_if_neq(entity_cell, Some(entity.id()))),
+            )
+            .content(spawn((
+                ImageNode {
+                    image: handle.clone(),
+                    image_mode: NodeImageMode::Stretch,
+                    ..Default::default()
+                },
+                Node {
+                    width,
+                    height,
+                    flex_shrink: 0.,
+                    ..Default::default()
+                },
+            )))
+    }
+}
+
+impl<'a> Modify<'a> for ImageView<'a> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
@@ -0,0 +1,289 @@
+use super::SubtreeAlpha;
+use crate::{
+    compose::{self, Compose},
+    data::Data,
+    ecs::{spawn, use_world, Modifier, Modify},
+    use_mut, use_ref, Scope, Signal, SignalMut,
+};
+use bevy_ecs::prelude::*;
+use bevy_time::Time;
+use bevy_ui::prelude::*;
+use std::{
+    cell::{Cell, RefCell},
+    mem,
+    rc::Rc,
+    time::Duration,
+};
+
+/// Transition for items entering or leaving an [`AnimatedList`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ListTransition {
+    /// Fade items in and out.
+    ///
+    /// This scales the alpha of every text, image, background, and border color in the item,
+    /// like [`opacity`](super::opacity).
+    #[default]
+    Fade,
+
+    /// Slide items in from, and out to, an offset in logical pixels.
+    Slide {
+        /// Horizontal offset.
+        x: f32,
+
+        /// Vertical offset.
+        y: f32,
+    },
+}
+
+/// Create an animated list of `items`.
+///
+/// New items run an enter transition, and removed items are kept alive until their exit transition completes.
+/// Items are compared by equality to find insertions and removals,
+/// and the state of each item's composable follows the item when items are inserted, removed, or reordered.
+///
+/// Each item is composed with `make_item` inside its own node.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Todos;
+///
+/// impl Compose for Todos {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let todos = use_mut(&cx, Vec::new);
+///
+///         (
+///             button(text::label("Add")).on_click(move || {
+///                 SignalMut::update(todos, |todos| {
+///                     let todo = format!("Todo {}", todos.len() + 1);
+///                     todos.push(todo)
+///                 })
+///             }),
+///             animated_list((*todos).clone(), |todo: &String| text::label(todo.clone()))
+///                 .transition(ListTransition::Slide { x: -40., y: 0. }),
+///         )
+///     }
+/// }
+/// ```
+pub fn animated_list<'a, T, C>(
+    items: Vec<T>,
+    make_item: impl Fn(&T) -> C + 'a,
+) -> AnimatedList<'a, T, C>
+where
+    T: Clone + PartialEq + Data + 'static,
+    C: Compose,
+{
+    AnimatedList {
+        items,
+        make_item: Rc::new(make_item),
+        transition: ListTransition::default(),
+        duration: Duration::from_millis(200),
+        modifier: Modifier::default(),
+    }
+}
+
+/// Animated list composable.
+///
+/// For more see [`animated_list`].
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct AnimatedList<'a, T, C> {
+    items: Vec<T>,
+    make_item: Rc<dyn Fn(&T) -> C + 'a>,
+    transition: ListTransition,
+    duration: Duration,
+    modifier: Modifier<'a>,
+}
+
+impl<T, C> AnimatedList<'_, T, C> {
+    /// Set the transition of this list (default: [`ListTransition::Fade`]).
+    pub fn transition(mut self, transition: ListTransition) -> Self {
+        self.transition = transition;
+        self
+    }
+
+    /// Set the duration of enter and exit transitions (default: 200ms).
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+unsafe impl<T: Data, C: Data> Data for AnimatedList<'_, T, C> {}
+
+struct Entry<T> {
+    /// Unique key of this entry, used to keep its composable's state when entries move.
+    key: u64,
+    item: T,
+    is_exiting: bool,
+    is_animating: bool,
+    start: Option<f32>,
+    visibility: f32,
+}
+
+struct ListState<T> {
+    entries: RefCell<Vec<Entry<T>>>,
+    next_key: Cell<u64>,
+}
+
+impl<T, C> Compose for AnimatedList<'_, T, C>
+where
+    T: Clone + PartialEq + Data + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let state: &ListState<T> = use_ref(&cx, || ListState {
+            entries: RefCell::new(Vec::new()),
+            next_key: Cell::new(0),
+        });
+
+        let tick = use_mut(&cx, || ());
+        use_world(&cx, move |time: Res<Time>| {
+            let duration = cx.me().duration.as_secs_f32();
+            let now = time.elapsed_secs();
+
+            let mut is_changed = false;
+            for entry in state
+                .entries
+                .borrow_mut()
+                .iter_mut()
+                .filter(|entry| entry.is_animating)
+            {
+                let start = *entry.start.get_or_insert(now);
+                let t = if duration > 0. {
+                    ((now - start) / duration).clamp(0., 1.)
+                } else {
+                    1.
+                };
+
+                // Ease out with a cubic curve.
+                let eased = 1. - (1. - t).powi(3);
+                entry.visibility = if entry.is_exiting { 1. - eased } else { eased };
+
+                if t >= 1. {
+                    entry.is_animating = false;
+                }
+                is_changed = true;
+            }
+
+            if is_changed {
+                SignalMut::set(tick, ());
+            }
+        });
+
+        let is_initial = use_ref(&cx, || Cell::new(true));
+        let mut entries = state.entries.borrow_mut();
+
+        // Remove items that have finished exiting.
+        entries.retain(|entry| entry.is_animating || !entry.is_exiting);
+
+        let mut old_entries: Vec<_> = mem::take(&mut *entries).into_iter().map(Some).collect();
+        for item in &cx.me().items {
+            let old_entry = old_entries
+                .iter_mut()
+                .find(|entry| entry.as_ref().is_some_and(|entry| entry.item == *item))
+                .and_then(Option::take);
+
+            let entry = match old_entry {
+                Some(mut entry) => {
+                    if entry.is_exiting {
+                        entry.is_exiting = false;
+                        entry.is_animating = true;
+                        entry.start = None;
+                    }
+                    entry
+                }
+                None => Entry {
+                    key: state.next_key.replace(state.next_key.get() + 1),
+                    item: item.clone(),
+                    is_exiting: false,
+                    is_animating: !is_initial.get(),
+                    start: None,
+                    visibility: if is_initial.get() { 1. } else { 0. },
+                },
+            };
+            entries.push(entry);
+        }
+
+        // Keep removed items at their previous positions until they finish exiting.
+        for (idx, old_entry) in old_entries.into_iter().enumerate() {
+            if let Some(mut entry) = old_entry {
+                if !entry.is_exiting {
+                    entry.is_exiting = true;
+                    entry.is_animating = true;
+                    entry.start = None;
+                }
+
+                let idx = idx.min(entries.len());
+                entries.insert(idx, entry);
+            }
+        }
+
+        is_initial.set(false);
+
+        // Items that finished exiting were removed above, so their composables are dropped here.
+        let keys: Vec<u64> = entries.iter().map(|entry| entry.key).collect();
+        drop(entries);
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn(Node {
+                flex_direction: FlexDirection::Column,
+                ..Default::default()
+            }))
+            .content(compose::from_iter_keyed(
+                keys,
+                |key| *key,
+                move |key: Signal<u64>| AnimatedListItem {
+                    key: *key,
+                    list: cx.me(),
+                    state,
+                },
+            ))
+    }
+}
+
+impl<'a, T, C> Modify<'a> for AnimatedList<'a, T, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
+
+struct AnimatedListItem<'s, 'a, T, C> {
+    key: u64,
+    list: Signal<'s, AnimatedList<'a, T, C>>,
+    state: &'s ListState<T>,
+}
+
+unsafe impl<T: Data, C: Data> Data for AnimatedListItem<'_, '_, T, C> {}
+
+impl<T, C> Compose for AnimatedListItem<'_, '_, T, C>
+where
+    T: Clone + PartialEq + Data + 'static,
+    C: Compose,
+{
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let list = cx.me().list;
+        let key = cx.me().key;
+
+        let entries = cx.me().state.entries.borrow();
+        let entry = entries.iter().find(|entry| entry.key == key)?;
+
+        let mut node = Node::default();
+        let mut alpha = 1.;
+        match list.transition {
+            ListTransition::Fade => alpha = entry.visibility,
+            ListTransition::Slide { x, y } => {
+                node.left = Val::Px((1. - entry.visibility) * x);
+                node.top = Val::Px((1. - entry.visibility) * y);
+            }
+        }
+
+        Some(spawn((node, SubtreeAlpha(alpha))).content((list.make_item)(&entry.item)))
+    }
+}
@@ -11,10 +11,18 @@ pub use self::container::{container, Container};
 mod radio;
 pub use self::radio::{radio_button, RadioButton};
 
+#[cfg(feature = "serde")]
+mod preset;
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use self::preset::{use_style_preset, PresetError, StylePreset};
+
 /// Text composables.
 pub mod text;
 
 /// Colors for a [`MaterialTheme`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Colors {
     /// Primary color.
     pub primary: Color,
@@ -27,6 +35,8 @@ pub struct Colors {
 }
 
 /// Typography style.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypographyStyle {
     /// Font size.
     pub font_size: f32,
@@ -40,6 +50,7 @@ pub struct TypographyStyle {
 
 /// Typography style kind.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypographyStyleKind {
     /// Small typography style.
     Small,
@@ -52,6 +63,8 @@ pub enum TypographyStyleKind {
 }
 
 /// Typography design token.
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TypographyToken {
     /// Small typography style.
     pub small: TypographyStyle,
@@ -77,6 +90,7 @@ impl Index<TypographyStyleKind> for TypographyToken {
 
 /// Typography kind.
 #[derive(Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TypographyKind {
     /// Body typography.
     Body,
@@ -92,6 +106,8 @@ pub enum TypographyKind {
 }
 
 /// Typography for a [`MaterialTheme`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Typography {
     /// Body typography.
     pub body: TypographyToken,
@@ -120,6 +136,15 @@ impl Index<TypographyKind> for Typography {
 }
 
 /// Material UI theme.
+///
+/// With the `serde` feature enabled, themes can be saved and loaded as presets in any Serde format.
+/// Missing fields in a preset are filled in from [`MaterialTheme::default`].
+/// Loaded presets should be checked with [`MaterialTheme::validate`]
+/// before being provided to composables with [`use_provider`](crate::use_provider),
+/// or loaded together with node styles as a [`StylePreset`].
+#[derive(Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct MaterialTheme {
     /// Theme colors.
     pub colors: Colors,
@@ -128,6 +153,43 @@ pub struct MaterialTheme {
     pub typography: Typography,
 }
 
+/// Error for an invalid [`MaterialTheme`].
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum ThemeError {
+    /// A typography style has an invalid font size.
+    #[error("Invalid font size for typography style: {0}")]
+    InvalidFontSize(f32),
+
+    /// A typography style has an invalid line height.
+    #[error("Invalid line height for typography style: {0}")]
+    InvalidLineHeight(f32),
+}
+
+impl MaterialTheme {
+    /// Validate this theme, returning an error for any invalid values.
+    pub fn validate(&self) -> Result<(), ThemeError> {
+        let typography = &self.typography;
+        for token in [
+            &typography.body,
+            &typography.headline,
+            &typography.label,
+            &typography.title,
+        ] {
+            for style in [&token.small, &token.medium, &token.large] {
+                if !style.font_size.is_finite() || style.font_size <= 0. {
+                    return Err(ThemeError::InvalidFontSize(style.font_size));
+                }
+
+                if !style.line_height.is_finite() || style.line_height < 0. {
+                    return Err(ThemeError::InvalidLineHeight(style.line_height));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl Default for MaterialTheme {
     fn default() -> Self {
         Self {
@@ -0,0 +1,200 @@
+use super::{MaterialTheme, ThemeError};
+use crate::ScopeState;
+use alloc::rc::Rc;
+use bevy_ui::Node;
+use core::any::TypeId;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::HashMap;
+
+/// Style preset of a [`MaterialTheme`] and named [`Node`] styles.
+///
+/// Presets can be saved and loaded in any Serde format,
+/// so spacing and colors can be tweaked without changing code.
+/// Missing fields in a preset are filled in from [`StylePreset::default`].
+///
+/// For more see [`use_style_preset`].
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StylePreset {
+    /// Theme of this preset.
+    pub theme: MaterialTheme,
+
+    /// Node styles of this preset by name.
+    pub nodes: HashMap<String, Node>,
+}
+
+impl StylePreset {
+    /// Load and validate a preset from a Serde `deserializer`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// let mut preset = StylePreset::default();
+    /// preset.nodes.insert(
+    ///     String::from("card"),
+    ///     Node {
+    ///         padding: UiRect::all(Val::Px(16.)),
+    ///         ..default()
+    ///     },
+    /// );
+    ///
+    /// let json = serde_json::to_string(&preset).unwrap();
+    /// let preset = StylePreset::load(&mut serde_json::Deserializer::from_str(&json)).unwrap();
+    /// assert_eq!(preset.node("card").unwrap().padding, UiRect::all(Val::Px(16.)));
+    ///
+    /// // Malformed presets return an error instead of panicking.
+    /// let json = r#"{ "theme": 1 }"#;
+    /// assert!(StylePreset::load(&mut serde_json::Deserializer::from_str(json)).is_err());
+    /// ```
+    pub fn load<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Self, PresetError> {
+        let preset =
+            Self::deserialize(deserializer).map_err(|e| PresetError::Parse(e.to_string()))?;
+        preset.validate()?;
+        Ok(preset)
+    }
+
+    /// Validate this preset, returning an error for any invalid values.
+    ///
+    /// ```
+    /// use actuate::prelude::*;
+    /// use bevy::prelude::*;
+    ///
+    /// let mut preset = StylePreset::default();
+    /// preset.nodes.insert(
+    ///     String::from("card"),
+    ///     Node {
+    ///         flex_grow: -1.,
+    ///         ..default()
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(
+    ///     preset.validate(),
+    ///     Err(PresetError::InvalidNode {
+    ///         name: String::from("card"),
+    ///         field: "flex_grow",
+    ///     })
+    /// );
+    /// ```
+    pub fn validate(&self) -> Result<(), PresetError> {
+        self.theme.validate()?;
+
+        for (name, node) in &self.nodes {
+            let invalid = |field| PresetError::InvalidNode {
+                name: name.clone(),
+                field,
+            };
+
+            if !node.flex_grow.is_finite() || node.flex_grow < 0. {
+                return Err(invalid("flex_grow"));
+            }
+
+            if !node.flex_shrink.is_finite() || node.flex_shrink < 0. {
+                return Err(invalid("flex_shrink"));
+            }
+
+            if node
+                .aspect_ratio
+                .is_some_and(|aspect_ratio| !aspect_ratio.is_finite() || aspect_ratio <= 0.)
+            {
+                return Err(invalid("aspect_ratio"));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Get the node style with the given `name`.
+    pub fn node(&self, name: &str) -> Option<&Node> {
+        self.nodes.get(name)
+    }
+}
+
+/// Error for an invalid [`StylePreset`].
+#[derive(Clone, Debug, PartialEq, thiserror::Error)]
+pub enum PresetError {
+    /// The preset could not be deserialized.
+    #[error("Failed to parse style preset: {0}")]
+    Parse(String),
+
+    /// The preset's theme is invalid.
+    #[error(transparent)]
+    Theme(#[from] ThemeError),
+
+    /// A node style of the preset has an invalid field.
+    #[error("Invalid `{field}` for node style `{name}`")]
+    InvalidNode {
+        /// Name of the node style.
+        name: String,
+
+        /// Name of the invalid field.
+        field: &'static str,
+    },
+}
+
+/// Use a style preset, providing its [`MaterialTheme`] and itself as context to child composables.
+///
+/// `load` will only be called once to load the preset (e.g. with [`StylePreset::load`]).
+/// If the preset fails to load, nothing is provided (so material composables use the default theme),
+/// and the error is returned to be reported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Card;
+///
+/// impl Compose for Card {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let node = use_context::<StylePreset>(&cx)
+///             .ok()
+///             .and_then(|preset| preset.node("card").cloned())
+///             .unwrap_or_default();
+///
+///         spawn(node).content(text::body("Themed"))
+///     }
+/// }
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let preset = use_style_preset(&cx, || {
+///             let json = std::fs::read_to_string("preset.json").unwrap_or_default();
+///             StylePreset::load(&mut serde_json::Deserializer::from_str(&json))
+///         });
+///
+///         (
+///             preset.err().map(|error| text::body(error.to_string())),
+///             Card,
+///         )
+///     }
+/// }
+/// ```
+pub fn use_style_preset(
+    cx: ScopeState<'_>,
+    load: impl FnOnce() -> Result<StylePreset, PresetError>,
+) -> Result<&Rc<StylePreset>, &PresetError> {
+    let preset = crate::use_ref(cx, || {
+        let preset = Rc::new(load()?);
+
+        let mut child_contexts = cx.child_contexts.borrow_mut();
+        child_contexts
+            .values
+            .insert(TypeId::of::<MaterialTheme>(), Rc::new(preset.theme.clone()));
+        child_contexts
+            .values
+            .insert(TypeId::of::<StylePreset>(), preset.clone());
+
+        Ok(preset)
+    });
+
+    preset.as_ref()
+}
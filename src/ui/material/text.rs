@@ -2,11 +2,80 @@ use super::{MaterialTheme, TypographyKind, TypographyStyleKind};
 use crate::{
     ecs::{spawn, Modifier, Modify},
     prelude::Compose,
-    use_context,
+    use_context, use_ref,
 };
 use actuate_macros::Data;
-use bevy_text::{TextColor, TextFont};
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::entity::Entity;
+use bevy_hierarchy::{BuildChildren, ChildBuild, Children, DespawnRecursiveExt};
+use bevy_text::{Font, TextColor, TextFont, TextSpan};
 use bevy_ui::prelude::Text as UiText;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+/// Context for fonts selected by family name.
+///
+/// Once provided with [`use_provider`](crate::use_provider),
+/// text composables can select a font with [`Text::font`] and [`Spans::font`].
+/// Text with a missing family (or without a provided context) falls back to Bevy's default font.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{prelude::*, ui::material::text::FontContext};
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct App;
+///
+/// impl Compose for App {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         // Fonts can also be added from TTF or OTF bytes with `Font::try_from_bytes`.
+///         let font = use_world_once(&cx, |asset_server: Res<AssetServer>| {
+///             asset_server.load("fonts/NotoSansJP-Regular.ttf")
+///         });
+///
+///         use_provider(&cx, || FontContext::default().with_font("Noto Sans JP", font.clone()));
+///
+///         text::headline("こんにちは").font("Noto Sans JP")
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FontContext {
+    fonts: HashMap<String, Handle<Font>>,
+}
+
+impl FontContext {
+    /// Add a font for the family `family`, replacing any previous font for that family.
+    pub fn with_font(mut self, family: impl Into<String>, font: Handle<Font>) -> Self {
+        self.insert(family, font);
+        self
+    }
+
+    /// Insert a font for the family `family`, returning the previous font for that family.
+    pub fn insert(
+        &mut self,
+        family: impl Into<String>,
+        font: Handle<Font>,
+    ) -> Option<Handle<Font>> {
+        self.fonts.insert(family.into(), font)
+    }
+
+    /// Get the font for the family `family`.
+    pub fn get(&self, family: &str) -> Option<&Handle<Font>> {
+        self.fonts.get(family)
+    }
+
+    /// Resolve the font for `family`, falling back to the default font.
+    fn resolve(me: Option<&Self>, family: Option<&str>) -> Handle<Font> {
+        me.zip(family)
+            .and_then(|(me, family)| me.get(family))
+            .cloned()
+            .unwrap_or_default()
+    }
+}
 
 /// Create a material UI text body.
 pub fn body<'a>(content: impl Into<String>) -> Text<'a> {
@@ -35,6 +104,7 @@ pub fn text<'a>(content: impl Into<String>) -> Text<'a> {
         modifier: Modifier::default(),
         typography: TypographyKind::Label,
         typography_style: TypographyStyleKind::Medium,
+        font: None,
     }
 }
 
@@ -45,6 +115,7 @@ pub struct Text<'a> {
     content: String,
     typography: TypographyKind,
     typography_style: TypographyStyleKind,
+    font: Option<String>,
     modifier: Modifier<'a>,
 }
 
@@ -60,6 +131,12 @@ impl Text<'_> {
         self.typography_style = typography_style;
         self
     }
+
+    /// Set the font family of this text from the current [`FontContext`] (default: Bevy's default font).
+    pub fn font(mut self, family: impl Into<String>) -> Self {
+        self.font = Some(family.into());
+        self
+    }
 }
 
 impl Compose for Text<'_> {
@@ -68,12 +145,15 @@ impl Compose for Text<'_> {
             .cloned()
             .unwrap_or_default();
 
+        let font_cx = use_context::<FontContext>(&cx).ok();
+
         let style = &theme.typography[cx.me().typography][cx.me().typography_style];
 
         spawn((
             UiText::new(cx.me().content.clone()),
             TextColor(theme.colors.text),
             TextFont {
+                font: FontContext::resolve(font_cx.map(|rc| &**rc), cx.me().font.as_deref()),
                 font_size: style.font_size,
                 ..Default::default()
             },
@@ -86,3 +166,250 @@ impl<'a> Modify<'a> for Text<'a> {
         &mut self.modifier
     }
 }
+
+/// Create a material UI text paragraph from multiple styled spans.
+///
+/// The spans are laid out together in one text block, sharing line wrapping.
+/// Any style not set on a [`Span`] is inherited from this paragraph's typography and font.
+/// Spans are only re-built when their content or style changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::{prelude::*, ui::material::text::FontContext};
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct ErrorMessage;
+///
+/// impl Compose for ErrorMessage {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let bold = use_world_once(&cx, |asset_server: Res<AssetServer>| {
+///             asset_server.load("fonts/FiraSans-Bold.ttf")
+///         });
+///         use_provider(&cx, || FontContext::default().with_font("Fira Sans Bold", bold.clone()));
+///
+///         text::spans([
+///             text::span("Error: ")
+///                 .color(Color::srgb(1., 0., 0.))
+///                 .font("Fira Sans Bold"),
+///             text::span("Failed to load file."),
+///         ])
+///     }
+/// }
+/// ```
+pub fn spans<'a>(spans: impl IntoIterator<Item = Span>) -> Spans<'a> {
+    Spans {
+        spans: spans.into_iter().collect(),
+        modifier: Modifier::default(),
+        typography: TypographyKind::Label,
+        typography_style: TypographyStyleKind::Medium,
+        font: None,
+    }
+}
+
+/// Create a styled span of text.
+///
+/// For more see [`spans`].
+pub fn span(content: impl Into<String>) -> Span {
+    Span {
+        content: content.into(),
+        color: None,
+        typography: None,
+        typography_style: None,
+        font: None,
+    }
+}
+
+/// Styled span of text.
+///
+/// For more see [`spans`].
+#[derive(Clone, Data)]
+#[actuate(path = "crate")]
+pub struct Span {
+    content: String,
+    color: Option<Color>,
+    typography: Option<TypographyKind>,
+    typography_style: Option<TypographyStyleKind>,
+    font: Option<String>,
+}
+
+impl Span {
+    /// Set the color of this span.
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the typography of this span.
+    pub fn typography(mut self, typography: TypographyKind) -> Self {
+        self.typography = Some(typography);
+        self
+    }
+
+    /// Set the typography style of this span.
+    pub fn typography_style(mut self, typography_style: TypographyStyleKind) -> Self {
+        self.typography_style = Some(typography_style);
+        self
+    }
+
+    /// Set the font family of this span from the current [`FontContext`] (e.g. a bold or italic font).
+    pub fn font(mut self, family: impl Into<String>) -> Self {
+        self.font = Some(family.into());
+        self
+    }
+}
+
+/// Resolved content and style of a [`Span`].
+#[derive(Clone, PartialEq)]
+struct SpanStyle {
+    content: String,
+    color: Color,
+    font: Handle<Font>,
+    font_size: f32,
+}
+
+impl SpanStyle {
+    fn bundle(&self) -> (TextSpan, TextColor, TextFont) {
+        (
+            TextSpan::new(self.content.clone()),
+            TextColor(self.color),
+            TextFont {
+                font: self.font.clone(),
+                font_size: self.font_size,
+                ..Default::default()
+            },
+        )
+    }
+}
+
+/// Material UI text paragraph composable.
+///
+/// For more see [`spans`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+pub struct Spans<'a> {
+    spans: Vec<Span>,
+    typography: TypographyKind,
+    typography_style: TypographyStyleKind,
+    font: Option<String>,
+    modifier: Modifier<'a>,
+}
+
+impl Spans<'_> {
+    /// Set the typography of this text.
+    pub fn typography(mut self, typography: TypographyKind) -> Self {
+        self.typography = typography;
+        self
+    }
+
+    /// Set the typography style of this text.
+    pub fn typography_style(mut self, typography_style: TypographyStyleKind) -> Self {
+        self.typography_style = typography_style;
+        self
+    }
+
+    /// Set the font family of this text from the current [`FontContext`] (default: Bevy's default font).
+    pub fn font(mut self, family: impl Into<String>) -> Self {
+        self.font = Some(family.into());
+        self
+    }
+}
+
+impl Compose for Spans<'_> {
+    fn compose(cx: crate::Scope<Self>) -> impl Compose {
+        let theme = use_context::<MaterialTheme>(&cx)
+            .cloned()
+            .unwrap_or_default();
+
+        let font_cx = use_context::<FontContext>(&cx).ok().map(|rc| &**rc);
+        let font = FontContext::resolve(font_cx, cx.me().font.as_deref());
+
+        let style = &theme.typography[cx.me().typography][cx.me().typography_style];
+        let font_size = style.font_size;
+
+        let spans: Vec<SpanStyle> = cx
+            .me()
+            .spans
+            .iter()
+            .map(|span| {
+                let style = &theme.typography[span.typography.unwrap_or(cx.me().typography)]
+                    [span.typography_style.unwrap_or(cx.me().typography_style)];
+
+                SpanStyle {
+                    content: span.content.clone(),
+                    color: span.color.unwrap_or(theme.colors.text),
+                    font: FontContext::resolve(
+                        font_cx,
+                        span.font.as_deref().or(cx.me().font.as_deref()),
+                    ),
+                    font_size: style.font_size,
+                }
+            })
+            .collect();
+
+        let last_spans: &RefCell<Option<Vec<SpanStyle>>> = use_ref(&cx, || RefCell::new(None));
+        let is_changed = last_spans.borrow().as_ref() != Some(&spans);
+        if is_changed {
+            *last_spans.borrow_mut() = Some(spans);
+        }
+
+        cx.me()
+            .modifier
+            .apply(spawn((
+                UiText::default(),
+                TextColor(theme.colors.text),
+                TextFont {
+                    font,
+                    font_size,
+                    ..Default::default()
+                },
+            )))
+            .on_insert(move |mut entity| {
+                if !is_changed {
+                    return;
+                }
+
+                let spans = last_spans.borrow();
+                let spans = spans.as_deref().unwrap_or_default();
+
+                let children: Vec<Entity> = entity
+                    .get::<Children>()
+                    .map(|children| {
+                        children
+                            .iter()
+                            .copied()
+                            .filter(|child| entity.world().get::<TextSpan>(*child).is_some())
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                if children.len() == spans.len() {
+                    // Update the existing spans in place.
+                    entity.world_scope(|world| {
+                        for (child, span) in children.iter().zip(spans) {
+                            world.entity_mut(*child).insert(span.bundle());
+                        }
+                    });
+                } else {
+                    entity.world_scope(|world| {
+                        for child in &children {
+                            world.entity_mut(*child).despawn_recursive();
+                        }
+                    });
+
+                    entity.with_children(|parent| {
+                        for span in spans {
+                            parent.spawn(span.bundle());
+                        }
+                    });
+                }
+            })
+    }
+}
+
+impl<'a> Modify<'a> for Spans<'a> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
@@ -0,0 +1,14 @@
+use bevy_math::Vec2;
+use bevy_ui::measurement::{Measure, MeasureArgs};
+use std::sync::Arc;
+
+pub(crate) type MeasureFn = Arc<dyn Fn(MeasureArgs) -> Vec2 + Send + Sync>;
+
+/// [`Measure`] of a node from a function, set with [`Modify::measure`](crate::ecs::Modify::measure).
+pub(crate) struct FnMeasure(pub(crate) MeasureFn);
+
+impl Measure for FnMeasure {
+    fn measure(&mut self, measure_args: MeasureArgs, _style: &taffy::Style) -> Vec2 {
+        (self.0)(measure_args)
+    }
+}
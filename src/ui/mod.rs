@@ -1,7 +1,7 @@
 use crate::{
-    ecs::{spawn, use_world, Modifier, Modify},
+    ecs::{spawn, use_world, Modifier, Modify, SpawnContext},
     prelude::Compose,
-    use_mut, Scope, Signal, SignalMut,
+    use_context, use_mut, use_ref, Scope, ScopeState, Signal, SignalMut,
 };
 use actuate_macros::Data;
 use bevy_ecs::prelude::*;
@@ -11,14 +11,139 @@ use bevy_input::{
 };
 use bevy_picking::prelude::*;
 use bevy_ui::prelude::*;
-use std::mem;
+use std::{cell::Cell, mem};
+
+mod image;
+pub use self::image::{image, image_rgba, ImageFit, ImageView};
+
+mod list;
+pub use self::list::{animated_list, AnimatedList, ListTransition};
+
+mod measure;
+pub(crate) use self::measure::{FnMeasure, MeasureFn};
+
+mod opacity;
+pub(crate) use self::opacity::{fade_subtrees, SubtreeAlpha};
+pub use self::opacity::{opacity, Opacity};
+
+mod rect;
+pub use self::rect::{rect, RectView};
+
+mod resize;
+pub(crate) use self::resize::{trigger_resized, LastSize, Resized};
 
 #[cfg(feature = "material")]
 #[cfg_attr(docsrs, doc(cfg(feature = "material")))]
 /// Material UI.
 pub mod material;
 
+/// Create a grid container.
+///
+/// Children of this grid can be placed with [`Modify::grid_row`] and [`Modify::grid_column`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Dashboard;
+///
+/// impl Compose for Dashboard {
+///     fn compose(_cx: Scope<Self>) -> impl Compose {
+///         grid((
+///             container(text::label("Wide")).grid_column(GridPlacement::span(2)),
+///             container(text::label("Small")),
+///         ))
+///         .columns(vec![RepeatedGridTrack::flex(3, 1.)])
+///     }
+/// }
+/// ```
+pub fn grid<'a, C: Compose>(content: C) -> Grid<'a, C> {
+    Grid {
+        content,
+        columns: Vec::new(),
+        rows: Vec::new(),
+        modifier: Modifier::default(),
+    }
+}
+
+#[derive(Data)]
+#[actuate(path = "crate")]
+/// Grid container composable.
+///
+/// For more see [`grid`].
+pub struct Grid<'a, C> {
+    content: C,
+    columns: Vec<RepeatedGridTrack>,
+    rows: Vec<RepeatedGridTrack>,
+    modifier: Modifier<'a>,
+}
+
+impl<C> Grid<'_, C> {
+    /// Set the column tracks of this grid (default: empty).
+    pub fn columns(mut self, columns: Vec<RepeatedGridTrack>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// Set the row tracks of this grid (default: empty).
+    pub fn rows(mut self, rows: Vec<RepeatedGridTrack>) -> Self {
+        self.rows = rows;
+        self
+    }
+}
+
+impl<C: Compose> Compose for Grid<'_, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn(Node {
+                display: Display::Grid,
+                grid_template_columns: cx.me().columns.clone(),
+                grid_template_rows: cx.me().rows.clone(),
+                ..Default::default()
+            }))
+            .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+    }
+}
+
+impl<'a, C: Compose> Modify<'a> for Grid<'a, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
+
 /// Create a scroll view.
+///
+/// Content can be scrolled with the mouse wheel while hovered, or by dragging it with a pointer.
+/// The scroll offset is clamped to the size of the content.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Feed;
+///
+/// impl Compose for Feed {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let offset = use_mut(&cx, || (0., 0.));
+///
+///         (
+///             spawn(Text::new("Back to top"))
+///                 .observe(move |_: Trigger<Pointer<Click>>| SignalMut::set(offset, (0., 0.))),
+///             scroll_view(compose::from_iter((0..100).collect::<Vec<_>>(), |i| spawn(Text::new(i.to_string()))))
+///                 .offset(offset),
+///         )
+///     }
+/// }
+/// ```
 pub fn scroll_view<'a, C: Compose>(content: C) -> ScrollView<'a, C> {
     ScrollView {
         content,
@@ -26,6 +151,7 @@ pub fn scroll_view<'a, C: Compose>(content: C) -> ScrollView<'a, C> {
         modifier: Modifier::default(),
         scroll_x: true,
         scroll_y: true,
+        offset: None,
     }
 }
 
@@ -37,10 +163,11 @@ pub struct ScrollView<'a, C> {
     line_size: f32,
     scroll_x: bool,
     scroll_y: bool,
+    offset: Option<SignalMut<'a, (f32, f32)>>,
     modifier: Modifier<'a>,
 }
 
-impl<C> ScrollView<'_, C> {
+impl<'a, C> ScrollView<'a, C> {
     /// Set the line size to scroll (default: 30).
     pub fn line_size(mut self, size: f32) -> Self {
         self.line_size = size;
@@ -58,6 +185,14 @@ impl<C> ScrollView<'_, C> {
         self.scroll_y = scroll_y;
         self
     }
+
+    /// Bind the scroll offset of this view to a signal, in logical pixels.
+    ///
+    /// The signal is updated as the view scrolls, and setting it scrolls the view.
+    pub fn offset(mut self, offset: SignalMut<'a, (f32, f32)>) -> Self {
+        self.offset = Some(offset);
+        self
+    }
 }
 
 impl<C: Compose> Compose for ScrollView<'_, C> {
@@ -66,6 +201,33 @@ impl<C: Compose> Compose for ScrollView<'_, C> {
 
         let entity_cell = use_mut(&cx, || None);
 
+        // Last offset synced between the bound signal and the scroll position.
+        let last_offset = use_ref(&cx, || Cell::new(None));
+        use_world(
+            &cx,
+            move |mut scrolled_node_query: Query<&mut ScrollPosition>| {
+                let Some(offset) = cx.me().offset else {
+                    return;
+                };
+                let Some(mut scroll_position) =
+                    (*entity_cell).and_then(|entity| scrolled_node_query.get_mut(entity).ok())
+                else {
+                    return;
+                };
+
+                let position = (scroll_position.offset_x, scroll_position.offset_y);
+                if last_offset.get() != Some(*offset) {
+                    // The signal was set, so scroll to the new offset.
+                    scroll_position.offset_x = offset.0;
+                    scroll_position.offset_y = offset.1;
+                    last_offset.set(Some(*offset));
+                } else if position != *offset {
+                    SignalMut::set(offset, position);
+                    last_offset.set(Some(position));
+                }
+            },
+        );
+
         use_world(
             &cx,
             move |mut mouse_wheel_events: EventReader<MouseWheel>,
@@ -105,6 +267,9 @@ impl<C: Compose> Compose for ScrollView<'_, C> {
             },
         );
 
+        let scroll_x = cx.me().scroll_x;
+        let scroll_y = cx.me().scroll_y;
+
         let modifier = &cx.me().modifier;
         let modifier: &Modifier = unsafe { mem::transmute(modifier) };
 
@@ -113,10 +278,38 @@ impl<C: Compose> Compose for ScrollView<'_, C> {
                 spawn(Node {
                     height: Val::Percent(100.),
                     flex_direction: FlexDirection::Column,
-                    overflow: Overflow::scroll_y(),
+                    overflow: Overflow {
+                        x: if scroll_x {
+                            OverflowAxis::Scroll
+                        } else {
+                            OverflowAxis::Clip
+                        },
+                        y: if scroll_y {
+                            OverflowAxis::Scroll
+                        } else {
+                            OverflowAxis::Clip
+                        },
+                    },
                     ..Default::default()
                 })
-                .on_spawn(move |entity| SignalMut::set(entity_cell, Some(entity.id())))
+                .on_insert(move |entity| SignalMut::set_if_neq(entity_cell, Some(entity.id())))
+                .observe(
+                    move |trigger: Trigger<Pointer<Drag>>,
+                          mut scrolled_node_query: Query<&mut ScrollPosition>| {
+                        let Ok(mut scroll_position) = scrolled_node_query.get_mut(trigger.entity())
+                        else {
+                            return;
+                        };
+
+                        if scroll_x {
+                            scroll_position.offset_x -= trigger.delta.x;
+                        }
+
+                        if scroll_y {
+                            scroll_position.offset_y -= trigger.delta.y;
+                        }
+                    },
+                )
                 .observe(move |_: Trigger<Pointer<Over>>| SignalMut::set(is_hovered, true))
                 .observe(move |_: Trigger<Pointer<Out>>| SignalMut::set(is_hovered, false)),
             )
@@ -129,3 +322,52 @@ impl<'a, C: Compose> Modify<'a> for ScrollView<'a, C> {
         &mut self.modifier
     }
 }
+
+/// Use the computed size of the parent node, in logical pixels.
+///
+/// The parent is the closest entity spawned by an ancestor composable (or the composition's target).
+/// The size is read from the previous layout pass, so it lags one frame behind layout changes,
+/// and is `None` until the parent has been laid out.
+///
+/// This will re-compose the current composable when the parent's size changes.
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Gallery;
+///
+/// impl Compose for Gallery {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let columns = use_parent_size(&cx)
+///             .map(|(width, _)| (width / 200.).max(1.) as u16)
+///             .unwrap_or(1);
+///
+///         grid(compose::from_iter(vec!["Photo"; 12], |label| text::label(label.to_string())))
+///             .columns(vec![RepeatedGridTrack::flex(columns, 1.)])
+///     }
+/// }
+/// ```
+pub fn use_parent_size(cx: ScopeState) -> Option<(f32, f32)> {
+    let parent_entity = use_context::<SpawnContext>(cx)
+        .ok()
+        .map(|spawn_cx| spawn_cx.parent_entity);
+    let size = use_mut(cx, || None);
+
+    use_world(cx, move |node_query: Query<&ComputedNode>| {
+        let new_size = parent_entity
+            .and_then(|entity| node_query.get(entity).ok())
+            .filter(|computed_node| !computed_node.is_empty())
+            .map(|computed_node| {
+                let size = computed_node.size() * computed_node.inverse_scale_factor();
+                (size.x, size.y)
+            });
+
+        SignalMut::set_if_neq(size, new_size);
+    });
+
+    *size
+}
@@ -0,0 +1,187 @@
+use crate::{
+    compose::Compose,
+    ecs::{spawn, Modifier, Modify},
+    Scope, Signal,
+};
+use actuate_macros::Data;
+use bevy_color::{Alpha, Color};
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{Children, HierarchyQueryExt, Parent};
+use bevy_text::TextColor;
+use bevy_ui::prelude::*;
+use std::mem;
+
+/// Create a composable that scales the opacity of its content.
+///
+/// This scales the alpha of every text, image, background, and border color in the content,
+/// between `0.` (fully transparent) and `1.` (unchanged).
+/// Nested opacity composables multiply, so content at `0.5` inside another at `0.5` is drawn at `0.25`.
+///
+/// # Examples
+///
+/// Crossfade between two views by stacking them with opposite opacities:
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Crossfade {
+///     progress: f32,
+/// }
+///
+/// impl Compose for Crossfade {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         let progress = cx.me().progress;
+///
+///         spawn(Node::default()).content((
+///             opacity(1. - progress, text::headline("Before")).position_type(PositionType::Absolute),
+///             opacity(progress, text::headline("After")).position_type(PositionType::Absolute),
+///         ))
+///     }
+/// }
+/// ```
+pub fn opacity<'a, C: Compose>(value: f32, content: C) -> Opacity<'a, C> {
+    Opacity {
+        value,
+        content,
+        modifier: Modifier::default(),
+    }
+}
+
+#[derive(Data)]
+#[actuate(path = "crate")]
+/// Opacity composable.
+///
+/// For more see [`opacity`].
+pub struct Opacity<'a, C> {
+    value: f32,
+    content: C,
+    modifier: Modifier<'a>,
+}
+
+impl<C: Compose> Compose for Opacity<'_, C> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let alpha = (*cx.me()).value.clamp(0., 1.);
+
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier
+            .apply(spawn((Node::default(), SubtreeAlpha(alpha))))
+            .content(unsafe { Signal::map_unchecked(cx.me(), |me| &me.content) })
+    }
+}
+
+impl<'a, C: Compose> Modify<'a> for Opacity<'a, C> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
+
+/// Alpha of an entity, applied to the colors of the entity and its descendants.
+#[derive(Clone, Copy, Component)]
+pub(crate) struct SubtreeAlpha(pub(crate) f32);
+
+/// Colors of a faded entity before its [`SubtreeAlpha`] was applied.
+#[derive(Clone, Copy, Default, PartialEq, Component)]
+pub(crate) struct FadeBase {
+    text: Option<Color>,
+    image: Option<Color>,
+    background: Option<Color>,
+    border: Option<Color>,
+}
+
+type FadeQueryData<'a> = (
+    Option<Mut<'a, TextColor>>,
+    Option<Mut<'a, ImageNode>>,
+    Option<Mut<'a, BackgroundColor>>,
+    Option<Mut<'a, BorderColor>>,
+    Option<&'a FadeBase>,
+);
+
+/// Apply the [`SubtreeAlpha`] of each entity to its descendants,
+/// multiplying the alpha of nested entities.
+///
+/// Changes are written without triggering change detection,
+/// so colors set by composables are always used as the base color.
+pub(crate) fn fade_subtrees(
+    mut commands: Commands,
+    alpha_query: Query<(Entity, &SubtreeAlpha)>,
+    parent_query: Query<&Parent>,
+    children_query: Query<&Children>,
+    mut color_query: Query<FadeQueryData>,
+) {
+    fn fade(color: Option<Mut<Color>>, base: Option<Color>, alpha: f32) -> Option<Color> {
+        let mut color = color?;
+        let base = if color.is_changed() {
+            *color
+        } else {
+            base.unwrap_or(*color)
+        };
+
+        let faded = base.with_alpha(base.alpha() * alpha);
+        if *color != faded {
+            *color.bypass_change_detection() = faded;
+        }
+
+        Some(base)
+    }
+
+    let mut stack = Vec::new();
+
+    for (root_entity, _) in &alpha_query {
+        // Nested entities are faded with their topmost ancestor.
+        if parent_query
+            .iter_ancestors(root_entity)
+            .any(|entity| alpha_query.contains(entity))
+        {
+            continue;
+        }
+
+        stack.push((root_entity, 1.));
+
+        while let Some((entity, parent_alpha)) = stack.pop() {
+            let alpha = alpha_query
+                .get(entity)
+                .map(|(_, alpha)| parent_alpha * alpha.0)
+                .unwrap_or(parent_alpha);
+
+            if let Ok(children) = children_query.get(entity) {
+                stack.extend(children.iter().map(|child| (*child, alpha)));
+            }
+
+            let Ok((text, image, background, border, base)) = color_query.get_mut(entity) else {
+                continue;
+            };
+            let base = base.copied().unwrap_or_default();
+
+            let new_base = FadeBase {
+                text: fade(
+                    text.map(|x| x.map_unchanged(|x| &mut x.0)),
+                    base.text,
+                    alpha,
+                ),
+                image: fade(
+                    image.map(|x| x.map_unchanged(|x| &mut x.color)),
+                    base.image,
+                    alpha,
+                ),
+                background: fade(
+                    background.map(|x| x.map_unchanged(|x| &mut x.0)),
+                    base.background,
+                    alpha,
+                ),
+                border: fade(
+                    border.map(|x| x.map_unchanged(|x| &mut x.0)),
+                    base.border,
+                    alpha,
+                ),
+            };
+
+            if new_base != base {
+                commands.entity(entity).insert(new_base);
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    compose::Compose,
+    ecs::{spawn, Modifier, Modify},
+    Scope,
+};
+use actuate_macros::Data;
+use bevy_color::Color;
+use bevy_ui::prelude::*;
+use std::mem;
+
+/// Create a rectangle composable filled with `color`.
+///
+/// The size of the rectangle comes from its [`Node`], which can be set with modifiers like [`Modify::width`].
+///
+/// # Examples
+///
+/// ```no_run
+/// use actuate::prelude::*;
+/// use bevy::prelude::*;
+///
+/// #[derive(Data)]
+/// struct Card;
+///
+/// impl Compose for Card {
+///     fn compose(cx: Scope<Self>) -> impl Compose {
+///         rect(Color::WHITE)
+///             .corner_radius(Val::Px(8.))
+///             .stroke(Val::Px(1.), Color::srgb(0.8, 0.8, 0.8))
+///             .width(Val::Px(200.))
+///             .height(Val::Px(120.))
+///     }
+/// }
+/// ```
+pub fn rect<'a>(color: Color) -> RectView<'a> {
+    RectView {
+        color,
+        corner_radius: Val::ZERO,
+        modifier: Modifier::default(),
+    }
+}
+
+/// Rectangle composable.
+///
+/// For more see [`rect`].
+#[derive(Data)]
+#[actuate(path = "crate")]
+#[must_use = "Composables do nothing unless composed or returned from other composables."]
+pub struct RectView<'a> {
+    color: Color,
+    corner_radius: Val,
+    modifier: Modifier<'a>,
+}
+
+impl RectView<'_> {
+    /// Set the radius of every corner of this rectangle (default: `Val::ZERO`).
+    pub fn corner_radius(mut self, corner_radius: Val) -> Self {
+        self.corner_radius = corner_radius;
+        self
+    }
+
+    /// Set the width and color of the border stroke of this rectangle (default: no stroke).
+    ///
+    /// This is shorthand for [`Modify::border`] on every side with [`Modify::border_color`].
+    pub fn stroke(self, width: Val, color: Color) -> Self {
+        self.border(UiRect::all(width)).border_color(color)
+    }
+}
+
+impl Compose for RectView<'_> {
+    fn compose(cx: Scope<Self>) -> impl Compose {
+        let modifier = &cx.me().modifier;
+        let modifier: &Modifier = unsafe { mem::transmute(modifier) };
+
+        modifier.apply(spawn((
+            Node::default(),
+            BackgroundColor(cx.me().color),
+            BorderRadius::all(cx.me().corner_radius),
+        )))
+    }
+}
+
+impl<'a> Modify<'a> for RectView<'a> {
+    fn modifier(&mut self) -> &mut Modifier<'a> {
+        &mut self.modifier
+    }
+}
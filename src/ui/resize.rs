@@ -0,0 +1,28 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::ComputedNode;
+
+/// Event triggered on an entity when the logical size of its computed node changes.
+#[derive(Clone, Copy, Event)]
+pub(crate) struct Resized {
+    pub(crate) size: (f32, f32),
+}
+
+/// Size of an entity observed for [`Resized`] events, from the last layout pass.
+#[derive(Clone, Copy, Default, Component)]
+pub(crate) struct LastSize(pub(crate) Option<(f32, f32)>);
+
+/// Trigger [`Resized`] on every entity with a [`LastSize`] whose logical size changed during layout.
+pub(crate) fn trigger_resized(
+    mut commands: Commands,
+    mut node_query: Query<(Entity, &ComputedNode, &mut LastSize), Changed<ComputedNode>>,
+) {
+    for (entity, computed_node, mut last_size) in &mut node_query {
+        let size = computed_node.size() * computed_node.inverse_scale_factor();
+        let size = (size.x, size.y);
+
+        if last_size.0 != Some(size) {
+            last_size.0 = Some(size);
+            commands.trigger_targets(Resized { size }, entity);
+        }
+    }
+}